@@ -0,0 +1,115 @@
+//! Derive macros for the [friendly](https://docs.rs/friendly) display library.
+//!
+//! These are usually used through the `derive` feature of `friendly`, which
+//! re-exports them.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, LitStr};
+
+/// How to display a field.
+enum Kind {
+    Plain,
+    Bytes,
+    Duration,
+    Scalar,
+    Integer,
+}
+
+/// Derive a `Display` implementation that lists a struct's fields in human-readable
+/// form, like “size: 1.2 GiB, elapsed: 3m2s”.
+///
+/// The caller's width, fill, and alignment apply to the whole line.  Fields are
+/// displayed with their own `Display` implementation unless annotated with one of:
+///
+/// - `#[friendly(bytes)]` — display with `friendly::bytes`
+/// - `#[friendly(duration)]` — display with `friendly::duration`
+/// - `#[friendly(scalar)]` — display with `friendly::scalar`
+/// - `#[friendly(integer)]` — display with `friendly::integer`
+/// - `#[friendly(skip)]` — omit the field
+/// - `#[friendly(label = "...")]` — use a different label than the field name
+#[proc_macro_derive(FriendlyDisplay, attributes(friendly))]
+pub fn derive_friendly_display(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(s) => match &s.fields {
+            Fields::Named(f) => &f.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "FriendlyDisplay requires named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "FriendlyDisplay can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut writes = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().expect("named field");
+        let mut kind = Kind::Plain;
+        let mut skip = false;
+        let mut label = ident.to_string();
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("friendly")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("bytes") {
+                    kind = Kind::Bytes;
+                } else if meta.path.is_ident("duration") {
+                    kind = Kind::Duration;
+                } else if meta.path.is_ident("scalar") {
+                    kind = Kind::Scalar;
+                } else if meta.path.is_ident("integer") {
+                    kind = Kind::Integer;
+                } else if meta.path.is_ident("skip") {
+                    skip = true;
+                } else if meta.path.is_ident("label") {
+                    label = meta.value()?.parse::<LitStr>()?.value();
+                } else {
+                    return Err(meta.error("unknown friendly attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        if skip {
+            continue;
+        }
+
+        let value = match kind {
+            Kind::Plain => quote!(&self.#ident),
+            Kind::Bytes => quote!(::friendly::bytes(self.#ident)),
+            Kind::Duration => quote!(::friendly::duration(self.#ident)),
+            Kind::Scalar => quote!(::friendly::scalar(self.#ident)),
+            Kind::Integer => quote!(::friendly::integer(self.#ident)),
+        };
+        let sep = if writes.is_empty() { "" } else { ", " };
+        let prefix = format!("{}{}: ", sep, label);
+        // fields are written without the caller's width, which pads the whole line
+        writes.push(quote! {
+            ::std::write!(buf, "{}{}", #prefix, #value)?;
+        });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::std::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                use ::std::fmt::Write;
+                let mut buf = ::std::string::String::new();
+                #(#writes)*
+                ::friendly::buffer::pad(f, &buf)
+            }
+        }
+    })
+}
@@ -0,0 +1,74 @@
+//! Column-aligned display of lists of quantities.
+//!
+//! The functions in this module display a list of values with one shared prefix and a
+//! common number of decimal places, padded to equal width so the values line up:
+//!
+//! ```
+//! # use friendly::{align, bytes};
+//! let sizes = [1_258_291u64, 3_565_158, 524_288];
+//! let col = align::quantities(sizes.iter().map(|s| bytes(*s)));
+//! assert_eq!(col, vec!["1.200 MiB", "3.400 MiB", "0.500 MiB"]);
+//! ```
+use crate::quantity::{QVal, Quantity};
+use crate::scale::{PrefixFamily, Scale};
+
+/// Display a list of numbers as an aligned column with SI prefixes (see
+/// [scalar](crate::scalar)).
+///
+/// ```
+/// # use friendly::align::column;
+/// let col = column(&[850.0, 1500.0, 12.5]);
+/// assert_eq!(col, vec!["0.850 k", "1.500 k", "0.013 k"]);
+/// ```
+pub fn column<Q: QVal + Clone>(values: &[Q]) -> Vec<String> {
+    quantities(values.iter().map(|v| crate::scalar(v.clone())))
+}
+
+/// Display a list of quantities as an aligned column.
+///
+/// The prefix and decimal places are chosen for the value with the largest
+/// magnitude, using its display options; each value keeps its own options otherwise.
+/// The strings are right-aligned to the width of the widest.
+pub fn quantities<Q, F, I>(values: I) -> Vec<String>
+where
+    Q: QVal,
+    F: PrefixFamily,
+    I: IntoIterator<Item = Quantity<Q, F>>,
+{
+    let values: Vec<_> = values.into_iter().collect();
+    let largest = values.iter().max_by(|a, b| {
+        let a = a.as_float().abs();
+        let b = b.as_float().abs();
+        a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let shared = largest.and_then(|q| q.resolve());
+
+    let strs: Vec<String> = values
+        .into_iter()
+        .map(|q| match shared {
+            Some((_v, prec, pfx)) => q.scale(Scale::<F>::Fixed(pfx)).decimals(prec).to_string(),
+            None => q.scale(Scale::<F>::Native).to_string(),
+        })
+        .collect();
+    let width = strs.iter().map(|s| s.chars().count()).max().unwrap_or(0);
+    strs.into_iter()
+        .map(|s| format!("{:>1$}", s, width))
+        .collect()
+}
+
+#[test]
+fn test_right_aligned() {
+    let col = column(&[5, 10, 120]);
+    assert_eq!(col, vec!["  5", " 10", "120"]);
+}
+
+#[test]
+fn test_empty() {
+    assert!(column::<f64>(&[]).is_empty());
+}
+
+#[test]
+fn test_negative() {
+    let col = quantities([-2500.0, 150.0].map(Quantity::decimal));
+    assert_eq!(col, vec!["-2.500 k", " 0.150 k"]);
+}
@@ -0,0 +1,108 @@
+//! Display of angles in decimal degrees or degrees-minutes-seconds.
+use std::fmt;
+
+/// An angle to display.
+///
+/// Angles are displayed in decimal degrees with 2 decimal places by default, or
+/// optionally in degrees, minutes, and seconds:
+///
+/// ```
+/// # use friendly::angle;
+/// assert_eq!(angle(123.456).to_string().as_str(), "123.46°");
+/// assert_eq!(angle(123.456).dms(true).to_string().as_str(), "123° 27′ 22″");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Angle {
+    degrees: f64,
+    dms: bool,
+    decimals: usize,
+    sec_decimals: usize,
+}
+
+/// Display an angle in degrees.
+pub fn angle(degrees: f64) -> Angle {
+    Angle::new(degrees)
+}
+
+impl Angle {
+    /// Create a new displayable angle in degrees.
+    pub fn new(degrees: f64) -> Angle {
+        Angle {
+            degrees,
+            dms: false,
+            decimals: 2,
+            sec_decimals: 0,
+        }
+    }
+
+    /// Create a new displayable angle from radians.
+    ///
+    /// ```
+    /// # use friendly::angle::Angle;
+    /// let a = Angle::from_radians(std::f64::consts::FRAC_PI_4);
+    /// assert_eq!(a.to_string().as_str(), "45.00°");
+    /// ```
+    pub fn from_radians(radians: f64) -> Angle {
+        Angle::new(radians.to_degrees())
+    }
+
+    /// Set whether to display degrees, minutes, and seconds.
+    pub fn dms(self, dms: bool) -> Angle {
+        Angle { dms, ..self }
+    }
+
+    /// Set the number of decimal places for decimal degrees.
+    pub fn decimals(self, decimals: usize) -> Angle {
+        Angle { decimals, ..self }
+    }
+
+    /// Set the number of decimal places for seconds in degrees-minutes-seconds form.
+    pub fn second_decimals(self, sec_decimals: usize) -> Angle {
+        Angle {
+            sec_decimals,
+            ..self
+        }
+    }
+
+    /// Get the angle in degrees.
+    pub fn degrees(&self) -> f64 {
+        self.degrees
+    }
+}
+
+impl fmt::Display for Angle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.dms {
+            return write!(f, "{:.*}°", self.decimals, self.degrees);
+        }
+
+        // round to the displayed precision first, so seconds never show as 60
+        let unit = 10f64.powi(self.sec_decimals as i32);
+        let total = (self.degrees.abs() * 3600.0 * unit).round() / unit;
+        let deg = (total / 3600.0).floor();
+        let min = ((total - deg * 3600.0) / 60.0).floor();
+        let sec = total - deg * 3600.0 - min * 60.0;
+        if self.degrees < 0.0 && total > 0.0 {
+            f.write_str("-")?;
+        }
+        write!(f, "{}° {}′ {:.*}″", deg, min, self.sec_decimals, sec)
+    }
+}
+
+#[test]
+fn test_negative_dms() {
+    let a = angle(-0.5).dms(true);
+    assert_eq!(a.to_string().as_str(), "-0° 30′ 0″");
+}
+
+#[test]
+fn test_dms_carry() {
+    let a = angle(10.999_99).dms(true);
+    assert_eq!(a.to_string().as_str(), "11° 0′ 0″");
+}
+
+#[test]
+fn test_second_decimals() {
+    let a = angle(123.456).dms(true).second_decimals(1);
+    assert_eq!(a.to_string().as_str(), "123° 27′ 21.6″");
+}
@@ -0,0 +1,59 @@
+//! Transliteration of display text to ASCII.
+use std::fmt;
+
+/// Get the ASCII replacement for a non-ASCII character, if it has one.
+fn replacement(c: char) -> Option<&'static str> {
+    match c {
+        // micro sign and Greek mu
+        'µ' | 'μ' => Some("u"),
+        '±' => Some("+/-"),
+        '×' => Some("x"),
+        '…' => Some("..."),
+        '‘' | '’' => Some("'"),
+        '“' | '”' => Some("\""),
+        '≥' => Some(">="),
+        '≤' => Some("<="),
+        '∞' => Some("inf"),
+        // non-breaking and narrow spaces
+        '\u{a0}' | '\u{2009}' | '\u{202f}' => Some(" "),
+        // dashes, hyphens, and the minus sign
+        '\u{2010}'..='\u{2015}' | '−' => Some("-"),
+        _ => None,
+    }
+}
+
+/// A writer that transliterates Unicode symbols and punctuation to ASCII.
+///
+/// Other non-ASCII text (such as unit words in other languages) is written as-is.
+pub(crate) struct AsciiWriter<'a, W: fmt::Write + ?Sized> {
+    out: &'a mut W,
+}
+
+impl<'a, W: fmt::Write + ?Sized> AsciiWriter<'a, W> {
+    pub(crate) fn new(out: &'a mut W) -> Self {
+        AsciiWriter { out }
+    }
+}
+
+impl<W: fmt::Write + ?Sized> fmt::Write for AsciiWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut rest = s;
+        while let Some((i, c, rep)) = rest
+            .char_indices()
+            .find_map(|(i, c)| replacement(c).map(|r| (i, c, r)))
+        {
+            self.out.write_str(&rest[..i])?;
+            self.out.write_str(rep)?;
+            rest = &rest[i + c.len_utf8()..];
+        }
+        self.out.write_str(rest)
+    }
+}
+
+#[test]
+fn test_transliterate() {
+    let mut out = String::new();
+    let mut w = AsciiWriter::new(&mut out);
+    fmt::Write::write_str(&mut w, "12.35 ± 0.14 μs, 1.2–3.4\u{a0}KiB…").unwrap();
+    assert_eq!(out.as_str(), "12.35 +/- 0.14 us, 1.2-3.4 KiB...");
+}
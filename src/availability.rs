@@ -0,0 +1,263 @@
+//! Availability and durability figures.
+use std::borrow::Cow;
+use std::fmt;
+use std::time::Duration;
+
+use crate::spacing::SpaceStyle;
+use crate::temporal::{seconds, HumanDuration};
+
+const YEAR_SECS: f64 = 365.25 * 24.0 * 3600.0;
+
+/// Periods over which downtime is measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Period {
+    /// An average (365.25-day) year.
+    Year,
+    /// An average month (1/12 of a year).
+    Month,
+    /// A week.
+    Week,
+    /// A day.
+    Day,
+}
+
+impl Period {
+    /// Get the length of this period in seconds.
+    pub fn seconds(&self) -> f64 {
+        match self {
+            Period::Year => YEAR_SECS,
+            Period::Month => YEAR_SECS / 12.0,
+            Period::Week => 7.0 * 24.0 * 3600.0,
+            Period::Day => 24.0 * 3600.0,
+        }
+    }
+}
+
+/// An availability (or durability) figure to display.
+///
+/// Availabilities are displayed as percentages with just enough precision to show
+/// the first digit that is not a 9:
+///
+/// ```
+/// # use friendly::availability;
+/// assert_eq!(availability(0.9999).to_string().as_str(), "99.99%");
+/// assert_eq!(availability(0.99995).to_string().as_str(), "99.995%");
+/// assert_eq!(availability(0.9999).words(true).to_string().as_str(), "four nines");
+/// ```
+///
+/// Like [percent](crate::percent()) and [odds](crate::odds), there is no space before
+/// the percent sign unless one is [requested](Availability::space), and NaN is
+/// displayed as “NaN” or its [placeholder](Availability::non_finite).
+#[derive(Debug, Clone)]
+pub struct Availability {
+    frac: f64,
+    words: bool,
+    spc: SpaceStyle,
+    non_finite: Option<Cow<'static, str>>,
+}
+
+/// Display an availability, expressed as a fraction of time (or objects) available.
+pub fn availability(frac: f64) -> Availability {
+    Availability::new(frac)
+}
+
+impl Availability {
+    /// Create a new availability from a fraction.
+    ///
+    /// The fraction is clamped to [0, 1], so impossible availabilities (such as from
+    /// more downtime than the period) display as “0%” or “100%”.
+    pub fn new(frac: f64) -> Availability {
+        Availability {
+            frac: frac.clamp(0.0, 1.0),
+            words: false,
+            spc: SpaceStyle::None,
+            non_finite: None,
+        }
+    }
+
+    /// Compute the availability that allows a given amount of downtime per period.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use friendly::availability::*;
+    /// let a = Availability::from_downtime(Duration::from_secs(8766), Period::Year);
+    /// assert_eq!(a.to_string().as_str(), "99.97%");
+    /// ```
+    pub fn from_downtime(downtime: Duration, period: Period) -> Availability {
+        Availability::new(1.0 - downtime.as_secs_f64() / period.seconds())
+    }
+
+    /// Set whether to describe whole (and half) numbers of nines in words.
+    ///
+    /// Availabilities that are not a whole or half number of nines are still
+    /// displayed as percentages.
+    pub fn words(self, words: bool) -> Availability {
+        Availability { words, ..self }
+    }
+
+    /// Set the space between the number and the percent sign.
+    ///
+    /// ```
+    /// # use friendly::availability;
+    /// assert_eq!(availability(0.9999).space(true).to_string().as_str(), "99.99 %");
+    /// ```
+    pub fn space<S: Into<SpaceStyle>>(self, space: S) -> Availability {
+        Availability {
+            spc: space.into(),
+            ..self
+        }
+    }
+
+    /// Display a placeholder in place of a NaN availability.
+    ///
+    /// ```
+    /// # use friendly::availability;
+    /// assert_eq!(availability(f64::NAN).non_finite("—").to_string().as_str(), "—");
+    /// ```
+    pub fn non_finite<S: Into<Cow<'static, str>>>(self, text: S) -> Availability {
+        Availability {
+            non_finite: Some(text.into()),
+            ..self
+        }
+    }
+
+    /// Get the availability fraction.
+    pub fn fraction(&self) -> f64 {
+        self.frac
+    }
+
+    /// Get the number of nines in this availability (e.g. 4 for 99.99%).
+    ///
+    /// This is fractional for availabilities that are not a whole number of nines.
+    pub fn nines(&self) -> f64 {
+        -(1.0 - self.frac).log10()
+    }
+
+    /// Get the allowed downtime per period.
+    ///
+    /// ```
+    /// # use friendly::availability::*;
+    /// let dt = availability(0.999).downtime(Period::Month);
+    /// assert_eq!(dt.to_string().as_str(), "43m49.80s");
+    /// ```
+    pub fn downtime(&self, period: Period) -> HumanDuration {
+        seconds((1.0 - self.frac) * period.seconds())
+    }
+
+    /// Get the (rounded) nines for display, if this is a whole or half number.
+    fn word_nines(&self) -> Option<(usize, bool)> {
+        let n = self.nines();
+        if !n.is_finite() || n < 0.5 {
+            return None;
+        }
+        let whole = n.round();
+        if (n - whole).abs() < 1e-6 {
+            return Some((whole as usize, false));
+        }
+        // half a nine is a 5 in the next digit, e.g. 99.95%
+        let half = n.ceil();
+        let unavail = (1.0 - self.frac) * 10f64.powf(half);
+        if (unavail - 5.0).abs() < 1e-6 {
+            Some((half as usize - 1, true))
+        } else {
+            None
+        }
+    }
+}
+
+const NUMBER_WORDS: &[&str] = &[
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+impl fmt::Display for Availability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.frac.is_nan() {
+            return f.write_str(self.non_finite.as_deref().unwrap_or("NaN"));
+        }
+        if self.words {
+            if let Some((n, half)) = self.word_nines().filter(|(n, _)| *n < NUMBER_WORDS.len()) {
+                let word = NUMBER_WORDS[n];
+                return match (n, half) {
+                    (0, true) => write!(f, "half a nine"),
+                    (1, false) => write!(f, "one nine"),
+                    (_, false) => write!(f, "{} nines", word),
+                    (_, true) => write!(f, "{} and a half nines", word),
+                };
+            }
+        }
+
+        let nines = self.nines();
+        let prec = if nines.is_finite() && nines > 0.0 {
+            // allow for floating-point noise in e.g. 1 - 0.9999
+            let digits = (nines - 1e-9).ceil() as i32 - 2;
+            digits.clamp(0, 15) as usize
+        } else {
+            0
+        };
+        let spc = self.spc.text().unwrap_or("");
+        write!(f, "{:.*}{}%", prec, self.frac * 100.0, spc)
+    }
+}
+
+#[test]
+fn test_three_nines() {
+    assert_eq!(availability(0.999).to_string().as_str(), "99.9%");
+}
+
+#[test]
+fn test_low() {
+    assert_eq!(availability(0.95).to_string().as_str(), "95%");
+    assert_eq!(availability(0.5).to_string().as_str(), "50%");
+}
+
+#[test]
+fn test_perfect() {
+    assert_eq!(availability(1.0).to_string().as_str(), "100%");
+}
+
+#[test]
+fn test_words() {
+    let a = availability(0.99999).words(true);
+    assert_eq!(a.to_string().as_str(), "five nines");
+    let a = availability(0.9).words(true);
+    assert_eq!(a.to_string().as_str(), "one nine");
+}
+
+#[test]
+fn test_half_words() {
+    let a = availability(0.9995).words(true);
+    assert_eq!(a.to_string().as_str(), "three and a half nines");
+}
+
+#[test]
+fn test_words_fallback() {
+    let a = availability(0.9993).words(true);
+    assert_eq!(a.to_string().as_str(), "99.93%");
+}
+
+#[test]
+fn test_downtime_year() {
+    let dt = availability(0.9999).downtime(Period::Year);
+    assert_eq!(dt.to_string().as_str(), "52m35.76s");
+}
+
+#[test]
+fn test_from_downtime() {
+    let a = Availability::from_downtime(Duration::from_millis(3_155_760), Period::Year);
+    assert_eq!(a.words(true).to_string().as_str(), "four nines");
+}
+
+#[test]
+fn test_out_of_range() {
+    assert_eq!(availability(-1.0).to_string().as_str(), "0%");
+    assert_eq!(availability(2.0).to_string().as_str(), "100%");
+    let a = Availability::from_downtime(Duration::from_secs(100_000), Period::Day);
+    assert_eq!(a.fraction(), 0.0);
+}
+
+#[test]
+fn test_nan() {
+    assert_eq!(availability(f64::NAN).to_string().as_str(), "NaN");
+    let a = availability(f64::NAN).words(true).non_finite("n/a");
+    assert_eq!(a.to_string().as_str(), "n/a");
+}
@@ -0,0 +1,194 @@
+//! Stack buffers for single-pass formatting.
+use std::fmt;
+
+/// Capacity of the inline formatting buffer.
+const CAPACITY: usize = 64;
+
+/// A small formatting buffer that lives on the stack.
+///
+/// Output is accumulated in an inline array so that a value can be formatted in one
+/// pass and emitted to the formatter with a single write.  If the output exceeds the
+/// inline capacity (e.g. with a very long suffix), it spills to a heap string.
+pub(crate) struct StackBuf {
+    buf: [u8; CAPACITY],
+    len: usize,
+    spill: Option<String>,
+}
+
+impl StackBuf {
+    pub(crate) fn new() -> StackBuf {
+        StackBuf {
+            buf: [0; CAPACITY],
+            len: 0,
+            spill: None,
+        }
+    }
+
+    /// Get the formatted contents.
+    pub(crate) fn as_str(&self) -> &str {
+        match &self.spill {
+            Some(s) => s.as_str(),
+            // SAFETY: the buffer is only filled by copying complete `str`s
+            None => unsafe { std::str::from_utf8_unchecked(&self.buf[..self.len]) },
+        }
+    }
+
+    /// Write the contents to a formatter with its width, fill, and alignment (see
+    /// [pad]).
+    pub(crate) fn pad(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad(f, self.as_str())
+    }
+}
+
+/// Write text to a formatter with its width, fill, and alignment.
+///
+/// Unlike [fmt::Formatter::pad], this ignores the precision, which would truncate
+/// the text (`{:.2}` would display “12.89 KiB” as “12”).  This is public for the
+/// `FriendlyDisplay` derive.
+#[doc(hidden)]
+pub fn pad(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    pad_parts(f, &[text], text.chars().count())
+}
+
+/// Write pieces of text, with a total width of `len` columns, to a formatter with its
+/// width, fill, and alignment (like [pad]).
+pub(crate) fn pad_parts(f: &mut fmt::Formatter<'_>, parts: &[&str], len: usize) -> fmt::Result {
+    let pad = f.width().filter(|w| *w > len).map_or(0, |w| w - len);
+    let (pre, post) = match f.align() {
+        Some(fmt::Alignment::Right) => (pad, 0),
+        Some(fmt::Alignment::Center) => (pad / 2, pad - pad / 2),
+        _ => (0, pad),
+    };
+    let fill = f.fill();
+    for _ in 0..pre {
+        fmt::Write::write_char(f, fill)?;
+    }
+    for part in parts {
+        f.write_str(part)?;
+    }
+    for _ in 0..post {
+        fmt::Write::write_char(f, fill)?;
+    }
+    Ok(())
+}
+
+impl fmt::Write for StackBuf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if let Some(spill) = &mut self.spill {
+            spill.push_str(s);
+        } else if self.len + s.len() <= CAPACITY {
+            self.buf[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+            self.len += s.len();
+        } else {
+            let mut spill = String::with_capacity(self.len + s.len());
+            spill.push_str(self.as_str());
+            spill.push_str(s);
+            self.spill = Some(spill);
+        }
+        Ok(())
+    }
+}
+
+/// A fixed-capacity formatting buffer that never allocates.
+///
+/// This can be reused to format many values without heap allocation; writes that
+/// would exceed its capacity fail with [fmt::Error].
+///
+/// ```
+/// # use friendly::bytes;
+/// # use friendly::buffer::FixedBuf;
+/// let mut buf = FixedBuf::<32>::new();
+/// bytes(13200).write_to(&mut buf).unwrap();
+/// assert_eq!(buf.as_str(), "12.89 KiB");
+/// buf.clear();
+/// bytes(182_421).write_to(&mut buf).unwrap();
+/// assert_eq!(buf.as_str(), "178.1 KiB");
+/// ```
+#[derive(Debug, Clone)]
+pub struct FixedBuf<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> FixedBuf<N> {
+    /// Create a new, empty buffer.
+    pub fn new() -> FixedBuf<N> {
+        FixedBuf {
+            buf: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Get the formatted contents.
+    pub fn as_str(&self) -> &str {
+        // SAFETY: the buffer is only filled by copying complete `str`s
+        unsafe { std::str::from_utf8_unchecked(&self.buf[..self.len]) }
+    }
+
+    /// Empty the buffer for reuse.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> Default for FixedBuf<N> {
+    fn default() -> Self {
+        FixedBuf::new()
+    }
+}
+
+impl<const N: usize> fmt::Write for FixedBuf<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.len + s.len() > N {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+use std::fmt::Write;
+
+#[test]
+fn test_inline() {
+    let mut buf = StackBuf::new();
+    write!(buf, "{:.2} KiB", 12.894).unwrap();
+    assert_eq!(buf.as_str(), "12.89 KiB");
+    assert!(buf.spill.is_none());
+}
+
+#[test]
+fn test_spill() {
+    let mut buf = StackBuf::new();
+    let long = "x".repeat(CAPACITY - 2);
+    buf.write_str("ab").unwrap();
+    buf.write_str(&long).unwrap();
+    assert!(buf.spill.is_none());
+    buf.write_str("μs").unwrap();
+    assert!(buf.spill.is_some());
+    assert_eq!(buf.as_str(), format!("ab{}μs", long));
+}
+
+#[test]
+fn test_fixed_overflow() {
+    let mut buf = FixedBuf::<4>::new();
+    assert!(buf.write_str("abc").is_ok());
+    assert!(buf.write_str("de").is_err());
+    assert_eq!(buf.as_str(), "abc");
+}
+
+#[test]
+fn test_pad() {
+    struct Text(&'static str);
+    impl fmt::Display for Text {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let mut buf = StackBuf::new();
+            fmt::Write::write_str(&mut buf, self.0)?;
+            buf.pad(f)
+        }
+    }
+    assert_eq!(format!("[{:^7}]", Text("μs")).as_str(), "[  μs   ]");
+    assert_eq!(format!("[{:*>4.1}]", Text("abc")).as_str(), "[*abc]");
+}
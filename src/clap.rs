@@ -0,0 +1,69 @@
+//! Value parsers for [clap] command-line arguments (with the `clap` feature).
+//!
+//! These parse arguments with the functions in [crate::parse], so flags accept the
+//! same formats this crate displays:
+//!
+//! ```
+//! # use std::time::Duration;
+//! use clap::{Arg, Command};
+//!
+//! let cmd = Command::new("fetch")
+//!     .arg(Arg::new("max-size").long("max-size").value_parser(friendly::clap::bytes()))
+//!     .arg(Arg::new("timeout").long("timeout").value_parser(friendly::clap::duration()));
+//! let m = cmd.get_matches_from(["fetch", "--max-size", "2GiB", "--timeout", "90s"]);
+//! assert_eq!(m.get_one::<u64>("max-size"), Some(&(2 << 30)));
+//! assert_eq!(m.get_one::<Duration>("timeout"), Some(&Duration::from_secs(90)));
+//! ```
+//!
+//! Invalid values are reported with the [ParseError](crate::parse::ParseError),
+//! including the accepted units where the unit was not recognized.
+use std::time::Duration;
+
+use ::clap::builder::TypedValueParser;
+
+use crate::parse::{parse_bytes, parse_duration, parse_integer, parse_scalar};
+
+/// Parse a size in bytes (see [parse_bytes]).
+pub fn bytes() -> impl TypedValueParser<Value = u64> {
+    parse_bytes
+}
+
+/// Parse a duration (see [parse_duration]).
+pub fn duration() -> impl TypedValueParser<Value = Duration> {
+    parse_duration
+}
+
+/// Parse a number with an optional SI prefix (see [parse_scalar]).
+pub fn scalar() -> impl TypedValueParser<Value = f64> {
+    parse_scalar
+}
+
+/// Parse an integer with an optional SI prefix (see [parse_integer]).
+pub fn integer() -> impl TypedValueParser<Value = i64> {
+    parse_integer
+}
+
+#[cfg(test)]
+fn command() -> ::clap::Command {
+    use ::clap::Arg;
+    ::clap::Command::new("test")
+        .arg(Arg::new("size").long("size").value_parser(bytes()))
+        .arg(Arg::new("count").long("count").value_parser(integer()))
+}
+
+#[test]
+fn test_integer() {
+    let m = command().get_matches_from(["test", "--count", "12k"]);
+    assert_eq!(m.get_one::<i64>("count"), Some(&12_000));
+}
+
+#[test]
+fn test_error_message() {
+    let err = command()
+        .try_get_matches_from(["test", "--size", "12 parsecs"])
+        .unwrap_err();
+    assert_eq!(err.kind(), ::clap::error::ErrorKind::ValueValidation);
+    let msg = err.to_string();
+    assert!(msg.contains("--size"), "{}", msg);
+    assert!(msg.contains("unknown unit “parsecs”"), "{}", msg);
+}
@@ -0,0 +1,149 @@
+//! Default display configuration.
+//!
+//! New [Quantity](crate::Quantity) and [HumanDuration](crate::temporal::HumanDuration)
+//! values take their initial options from the current defaults, which can be set
+//! for the whole process or overridden on the current thread:
+//!
+//! ```
+//! # use friendly::{bytes, config};
+//! # use friendly::config::Config;
+//! let cfg = Config::default().sig_figs(3).space(false);
+//! config::with_defaults(cfg, || {
+//!     assert_eq!(bytes(13200).to_string().as_str(), "12.9KiB");
+//! });
+//! assert_eq!(bytes(13200).to_string().as_str(), "12.89 KiB");
+//! ```
+//!
+//! Options set on individual values always take precedence over the defaults.
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+
+use crate::lang::Lang;
+use crate::spacing::SpaceStyle;
+
+/// Default display options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub(crate) nsig: u32,
+    pub(crate) spc: SpaceStyle,
+    pub(crate) ascii: bool,
+    pub(crate) lang: Lang,
+    pub(crate) point: char,
+}
+
+impl Config {
+    /// The built-in defaults.
+    pub const DEFAULT: Config = Config {
+        nsig: 4,
+        spc: SpaceStyle::Space,
+        ascii: false,
+        lang: Lang::En,
+        point: '.',
+    };
+
+    /// Set the default number of significant figures for quantities.
+    pub fn sig_figs(self, nsig: u32) -> Config {
+        Config { nsig, ..self }
+    }
+
+    /// Set the space quantities include before units.
+    pub fn space<S: Into<SpaceStyle>>(self, space: S) -> Config {
+        Config {
+            spc: space.into(),
+            ..self
+        }
+    }
+
+    /// Set whether quantities and durations write ASCII-only output.
+    pub fn ascii(self, ascii: bool) -> Config {
+        Config { ascii, ..self }
+    }
+
+    /// Set the language for durations.
+    pub fn lang(self, lang: Lang) -> Config {
+        Config { lang, ..self }
+    }
+
+    /// Set the decimal separator.
+    pub fn decimal_separator(self, point: char) -> Config {
+        Config { point, ..self }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config::DEFAULT
+    }
+}
+
+static GLOBAL: RwLock<Config> = RwLock::new(Config::DEFAULT);
+/// Whether the process-wide defaults have been set, so values can be created without
+/// taking the lock until they are.
+static GLOBAL_SET: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static LOCAL: Cell<Option<Config>> = const { Cell::new(None) };
+}
+
+/// Set the process-wide defaults.
+pub fn set_defaults(cfg: Config) {
+    *GLOBAL.write().unwrap_or_else(|e| e.into_inner()) = cfg;
+    GLOBAL_SET.store(true, Ordering::Release);
+}
+
+/// Get the defaults in effect on the current thread.
+pub fn defaults() -> Config {
+    LOCAL.with(|l| l.get()).unwrap_or_else(|| {
+        if GLOBAL_SET.load(Ordering::Acquire) {
+            *GLOBAL.read().unwrap_or_else(|e| e.into_inner())
+        } else {
+            Config::DEFAULT
+        }
+    })
+}
+
+/// Run a function with different defaults on the current thread.
+pub fn with_defaults<R, F: FnOnce() -> R>(cfg: Config, f: F) -> R {
+    /// Restores the previous defaults, even if the function panics.
+    struct Restore(Option<Config>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            LOCAL.with(|l| l.set(self.0));
+        }
+    }
+
+    let _restore = Restore(LOCAL.with(|l| l.replace(Some(cfg))));
+    f()
+}
+
+#[test]
+fn test_nested() {
+    let outer = Config::default().sig_figs(2);
+    with_defaults(outer, || {
+        assert_eq!(defaults().nsig, 2);
+        with_defaults(outer.lang(Lang::De), || {
+            assert_eq!(defaults().lang, Lang::De);
+        });
+        assert_eq!(defaults(), outer);
+    });
+}
+
+#[test]
+fn test_duration_defaults() {
+    let cfg = Config::default().lang(Lang::De).decimal_separator(',');
+    let d = with_defaults(cfg, || crate::seconds(92.5).compact(false));
+    assert_eq!(d.to_string().as_str(), "1 Minute 32,50 Sekunden");
+}
+
+#[test]
+fn test_duration_snapshot() {
+    // sub-minute durations use the defaults from when they were created
+    let cfg = Config::default().sig_figs(2);
+    let d = with_defaults(cfg, || crate::seconds(5.29314));
+    assert_eq!(d.to_string().as_str(), "5.3s");
+    let d = crate::seconds(5.29314);
+    let s = with_defaults(cfg, || d.to_string());
+    assert_eq!(s.as_str(), "5.293s");
+}
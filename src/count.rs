@@ -0,0 +1,93 @@
+//! Compact, social-media style display of counts.
+use std::fmt;
+
+use crate::quantity::QVal;
+
+/// Suffixes for compact counts, by power of 1000.
+const SUFFIXES: &[&str] = &["", "K", "M", "B", "T"];
+
+/// A count to display in compact form (“1.2K”, “5.6M”).
+///
+/// Counts under 1000 are displayed exactly; larger counts are abbreviated with an
+/// uppercase suffix and one decimal place by default, dropping trailing zeros:
+///
+/// ```
+/// # use friendly::count;
+/// assert_eq!(count(532).to_string().as_str(), "532");
+/// assert_eq!(count(1234).to_string().as_str(), "1.2K");
+/// assert_eq!(count(5_600_000).to_string().as_str(), "5.6M");
+/// assert_eq!(count(2_000_000_000u64).to_string().as_str(), "2B");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Count<V: QVal> {
+    value: V,
+    decimals: usize,
+}
+
+/// Display a count in compact form.
+pub fn count<V: QVal>(value: V) -> Count<V> {
+    Count::new(value)
+}
+
+impl<V: QVal> Count<V> {
+    /// Create a new compact count.
+    pub fn new(value: V) -> Self {
+        Count { value, decimals: 1 }
+    }
+
+    /// Set the maximum number of decimal places for abbreviated counts.
+    pub fn decimals(self, decimals: usize) -> Self {
+        Count { decimals, ..self }
+    }
+}
+
+impl<V: QVal> fmt::Display for Count<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let val = self.value.as_float();
+        if !val.is_finite() || val.abs() < 1000.0 {
+            return write!(f, "{}", self.value);
+        }
+
+        let round = 10f64.powi(self.decimals as i32);
+        let mut i = 0;
+        let mut scaled = val;
+        // rounding can carry into the next suffix (999,960 is 1M, not 1000K)
+        while i + 1 < SUFFIXES.len() && (scaled * round).round().abs() >= 1000.0 * round {
+            scaled /= 1000.0;
+            i += 1;
+        }
+
+        let num = format!("{:.*}", self.decimals, scaled);
+        let num = if num.contains('.') {
+            num.trim_end_matches('0').trim_end_matches('.')
+        } else {
+            &num
+        };
+        write!(f, "{}{}", num, SUFFIXES[i])
+    }
+}
+
+#[test]
+fn test_exact_small() {
+    assert_eq!(count(0).to_string().as_str(), "0");
+    assert_eq!(count(999).to_string().as_str(), "999");
+    assert_eq!(count(-42).to_string().as_str(), "-42");
+}
+
+#[test]
+fn test_carry() {
+    assert_eq!(count(999_960).to_string().as_str(), "1M");
+    assert_eq!(count(999_940).to_string().as_str(), "999.9K");
+}
+
+#[test]
+fn test_decimals() {
+    assert_eq!(count(123_456).decimals(0).to_string().as_str(), "123K");
+    assert_eq!(count(1_234_567).decimals(2).to_string().as_str(), "1.23M");
+    assert_eq!(count(-1500).to_string().as_str(), "-1.5K");
+}
+
+#[test]
+fn test_trillions() {
+    assert_eq!(count(4.2e15).to_string().as_str(), "4200T");
+}
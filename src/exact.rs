@@ -0,0 +1,95 @@
+//! Exact display of counts with digit grouping.
+use std::fmt;
+
+use crate::grouping::write_grouped;
+use crate::quantity::QVal;
+
+/// An exact (unscaled) count to display.
+///
+/// Exact counts are written in full, with digits grouped for readability, followed by
+/// a unit word that agrees with the count:
+///
+/// ```
+/// # use friendly::exact::Exact;
+/// let e = Exact::new(13200).units("file", "files");
+/// assert_eq!(e.to_string().as_str(), "13,200 files");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Exact<V: QVal> {
+    value: V,
+    singular: &'static str,
+    plural: &'static str,
+    sep: char,
+}
+
+impl<V: QVal> Exact<V> {
+    /// Create a new exact count without units.
+    pub fn new(value: V) -> Self {
+        Exact {
+            value,
+            singular: "",
+            plural: "",
+            sep: ',',
+        }
+    }
+
+    /// Set the singular and plural unit words.
+    pub fn units(self, singular: &'static str, plural: &'static str) -> Self {
+        Exact {
+            singular,
+            plural,
+            ..self
+        }
+    }
+
+    /// Set the digit group separator.
+    pub fn separator(self, sep: char) -> Self {
+        Exact { sep, ..self }
+    }
+}
+
+impl<V: QVal> fmt::Display for Exact<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let num = self.value.to_string();
+        write_grouped(f, &num, self.sep)?;
+        let unit = if num.trim_start_matches(['-', '+']) == "1" {
+            self.singular
+        } else {
+            self.plural
+        };
+        if !unit.is_empty() {
+            write!(f, " {}", unit)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_no_units() {
+    let e = Exact::new(1234567);
+    assert_eq!(e.to_string().as_str(), "1,234,567");
+}
+
+#[test]
+fn test_singular() {
+    let e = Exact::new(1).units("byte", "bytes");
+    assert_eq!(e.to_string().as_str(), "1 byte");
+}
+
+#[test]
+fn test_negative_singular() {
+    let e = Exact::new(-1).units("byte", "bytes");
+    assert_eq!(e.to_string().as_str(), "-1 byte");
+}
+
+#[test]
+fn test_zero_plural() {
+    let e = Exact::new(0).units("byte", "bytes");
+    assert_eq!(e.to_string().as_str(), "0 bytes");
+}
+
+#[test]
+fn test_separator() {
+    let e = Exact::new(13200u64).units("byte", "bytes").separator('.');
+    assert_eq!(e.to_string().as_str(), "13.200 bytes");
+}
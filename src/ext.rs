@@ -0,0 +1,52 @@
+//! Extension traits to humanize values with method calls.
+//!
+//! These are usually imported with the [prelude](crate::prelude):
+//!
+//! ```
+//! # use std::time::Duration;
+//! use friendly::prelude::*;
+//! assert_eq!(13200u64.human_bytes().to_string().as_str(), "12.89 KiB");
+//! assert_eq!(0.023.human_scalar().to_string().as_str(), "23.00 m");
+//! assert_eq!(Duration::from_secs(90).human().to_string().as_str(), "1m30.00s");
+//! ```
+use crate::quantity::{QVal, Quantity};
+use crate::scale::{Binary, Decimal};
+use crate::temporal::HumanDuration;
+
+/// Extension methods to display numbers.
+pub trait Friendly: QVal + Sized {
+    /// Display as a number of bytes (see [crate::bytes]).
+    fn human_bytes(self) -> Quantity<Self, Binary> {
+        crate::bytes(self)
+    }
+
+    /// Display as an auto-scaled value (see [crate::scalar]).
+    fn human_scalar(self) -> Quantity<Self, Decimal> {
+        crate::scalar(self)
+    }
+
+    /// Display as an auto-scaled integer (see [crate::integer]).
+    fn human_integer(self) -> Quantity<Self, Decimal> {
+        crate::integer(self)
+    }
+}
+
+impl<T: QVal> Friendly for T {}
+
+/// Extension method to display durations.
+pub trait FriendlyDuration {
+    /// Display as a human-readable duration (see [crate::duration]).
+    fn human(&self) -> HumanDuration;
+}
+
+impl<D: Into<HumanDuration> + Copy> FriendlyDuration for D {
+    fn human(&self) -> HumanDuration {
+        crate::duration(*self)
+    }
+}
+
+#[test]
+fn test_integer() {
+    assert_eq!(15_250i32.human_integer().to_string().as_str(), "15.25 k");
+    assert_eq!(532i32.human_integer().to_string().as_str(), "532");
+}
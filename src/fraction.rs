@@ -0,0 +1,186 @@
+//! Display of numbers as simple fractions.
+use std::fmt;
+
+/// Unicode vulgar fraction characters, as (numerator, denominator, character).
+const VULGAR: &[(u64, u64, char)] = &[
+    (1, 2, '½'),
+    (1, 3, '⅓'),
+    (2, 3, '⅔'),
+    (1, 4, '¼'),
+    (3, 4, '¾'),
+    (1, 5, '⅕'),
+    (2, 5, '⅖'),
+    (3, 5, '⅗'),
+    (4, 5, '⅘'),
+    (1, 6, '⅙'),
+    (5, 6, '⅚'),
+    (1, 7, '⅐'),
+    (1, 8, '⅛'),
+    (3, 8, '⅜'),
+    (5, 8, '⅝'),
+    (7, 8, '⅞'),
+    (1, 9, '⅑'),
+    (1, 10, '⅒'),
+];
+
+const SUPERSCRIPT: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+const SUBSCRIPT: [char; 10] = ['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'];
+
+/// A number to display as the nearest simple fraction.
+///
+/// The fraction is the one with the smallest denominator (up to a maximum of 16 by
+/// default) within a tolerance (0.01 by default) of the value:
+///
+/// ```
+/// # use friendly::fraction;
+/// assert_eq!(fraction(0.75).to_string().as_str(), "3/4");
+/// assert_eq!(fraction(0.75).unicode(true).to_string().as_str(), "¾");
+/// assert_eq!(fraction(1.333).mixed(true).unicode(true).to_string().as_str(), "1 ⅓");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Fraction {
+    value: f64,
+    max_denom: u64,
+    tolerance: f64,
+    unicode: bool,
+    mixed: bool,
+}
+
+/// Display a number as a fraction.
+pub fn fraction(value: f64) -> Fraction {
+    Fraction::new(value)
+}
+
+impl Fraction {
+    /// Create a new displayable fraction.
+    pub fn new(value: f64) -> Fraction {
+        Fraction {
+            value,
+            max_denom: 16,
+            tolerance: 0.01,
+            unicode: false,
+            mixed: false,
+        }
+    }
+
+    /// Set the largest denominator to consider.
+    pub fn max_denominator(self, max_denom: u64) -> Fraction {
+        Fraction {
+            max_denom: max_denom.max(1),
+            ..self
+        }
+    }
+
+    /// Set how close the fraction must be to the value.  If no fraction is close
+    /// enough, the nearest one with an allowed denominator is used.
+    pub fn tolerance(self, tolerance: f64) -> Fraction {
+        Fraction { tolerance, ..self }
+    }
+
+    /// Set whether to use Unicode fraction characters (“¾”, “⁵⁄₁₆”).
+    pub fn unicode(self, unicode: bool) -> Fraction {
+        Fraction { unicode, ..self }
+    }
+
+    /// Set whether to display values above 1 as mixed numbers (“1 1/3” instead
+    /// of “4/3”).
+    pub fn mixed(self, mixed: bool) -> Fraction {
+        Fraction { mixed, ..self }
+    }
+
+    /// Get the numerator and denominator of the (unsigned) fraction.
+    pub fn parts(&self) -> (u64, u64) {
+        let abs = self.value.abs();
+        let mut best = (abs.round() as u64, 1);
+        let mut best_err = (abs - abs.round()).abs();
+        for d in 1..=self.max_denom {
+            let n = (abs * d as f64).round();
+            let err = (abs - n / d as f64).abs();
+            if err <= self.tolerance {
+                return (n as u64, d);
+            } else if err < best_err {
+                best = (n as u64, d);
+                best_err = err;
+            }
+        }
+        best
+    }
+}
+
+fn write_digits(f: &mut fmt::Formatter<'_>, num: u64, digits: &[char; 10]) -> fmt::Result {
+    for c in num.to_string().bytes() {
+        write!(f, "{}", digits[(c - b'0') as usize])?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Fraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (mut num, denom) = self.parts();
+        if num > 0 && self.value < 0.0 {
+            f.write_str("-")?;
+        }
+        if num % denom == 0 {
+            return write!(f, "{}", num / denom);
+        }
+
+        if self.mixed && num > denom {
+            write!(f, "{} ", num / denom)?;
+            num %= denom;
+        }
+
+        if self.unicode {
+            if let Some((_, _, c)) = VULGAR.iter().find(|(n, d, _)| *n == num && *d == denom) {
+                write!(f, "{}", c)
+            } else {
+                write_digits(f, num, &SUPERSCRIPT)?;
+                f.write_str("⁄")?;
+                write_digits(f, denom, &SUBSCRIPT)
+            }
+        } else {
+            write!(f, "{}/{}", num, denom)
+        }
+    }
+}
+
+#[test]
+fn test_simple() {
+    assert_eq!(fraction(0.5).to_string().as_str(), "1/2");
+    assert_eq!(fraction(0.333).to_string().as_str(), "1/3");
+    assert_eq!(fraction(0.6667).to_string().as_str(), "2/3");
+}
+
+#[test]
+fn test_whole() {
+    assert_eq!(fraction(0.0).to_string().as_str(), "0");
+    assert_eq!(fraction(3.002).to_string().as_str(), "3");
+    assert_eq!(fraction(-2.0).to_string().as_str(), "-2");
+}
+
+#[test]
+fn test_improper() {
+    assert_eq!(fraction(1.5).to_string().as_str(), "3/2");
+    assert_eq!(fraction(-1.5).mixed(true).to_string().as_str(), "-1 1/2");
+    assert_eq!(
+        fraction(2.25)
+            .mixed(true)
+            .unicode(true)
+            .to_string()
+            .as_str(),
+        "2 ¼"
+    );
+}
+
+#[test]
+fn test_unicode_fallback() {
+    let f = fraction(0.3125).tolerance(0.0).unicode(true);
+    assert_eq!(f.to_string().as_str(), "⁵⁄₁₆");
+}
+
+#[test]
+fn test_nearest() {
+    let f = fraction(0.3).max_denominator(4);
+    assert_eq!(f.parts(), (1, 3));
+    let f = fraction(0.3).tolerance(0.001);
+    assert_eq!(f.parts(), (3, 10));
+}
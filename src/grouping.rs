@@ -0,0 +1,126 @@
+//! Digit grouping support.
+use std::fmt;
+
+/// Separators for grouping digits into thousands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Grouping {
+    /// Do not group digits (“1234567”).
+    #[default]
+    None,
+    /// Group with commas (“1,234,567”).
+    Comma,
+    /// Group with periods (“1.234.567”).
+    Period,
+    /// Group with spaces (“1 234 567”).
+    Space,
+    /// Group with underscores (“1_234_567”).
+    Underscore,
+    /// Group with apostrophes (“1'234'567”).
+    Apostrophe,
+}
+
+impl Grouping {
+    /// Get the separator character for this grouping, if any.
+    pub fn separator(&self) -> Option<char> {
+        match self {
+            Grouping::None => None,
+            Grouping::Comma => Some(','),
+            Grouping::Period => Some('.'),
+            Grouping::Space => Some(' '),
+            Grouping::Underscore => Some('_'),
+            Grouping::Apostrophe => Some('\''),
+        }
+    }
+}
+
+/// `true` groups with commas, and `false` disables grouping.
+impl From<bool> for Grouping {
+    fn from(group: bool) -> Grouping {
+        if group {
+            Grouping::Comma
+        } else {
+            Grouping::None
+        }
+    }
+}
+
+/// Write a formatted number, inserting a separator between groups of three digits
+/// in its integer part.
+///
+/// The number may have a leading sign, and anything after the integer digits (a
+/// decimal point, fractional digits, or exponent) is written unchanged.
+pub(crate) fn write_grouped<W: fmt::Write>(w: &mut W, num: &str, sep: char) -> fmt::Result {
+    write_number(w, num, Some(sep), '.')
+}
+
+/// Write a formatted number with optional digit grouping and a decimal separator.
+///
+/// `num` is a number as formatted by Rust (with `.` as its decimal point); the
+/// decimal point is replaced with `point`.
+pub(crate) fn write_number<W: fmt::Write>(
+    w: &mut W,
+    num: &str,
+    sep: Option<char>,
+    point: char,
+) -> fmt::Result {
+    let digits_start = num.find(|c: char| c.is_ascii_digit()).unwrap_or(num.len());
+    let digits_end = num[digits_start..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map(|i| i + digits_start)
+        .unwrap_or(num.len());
+
+    w.write_str(&num[..digits_start])?;
+    let digits = &num[digits_start..digits_end];
+    match sep {
+        Some(sep) => {
+            for (i, c) in digits.chars().enumerate() {
+                if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                    w.write_char(sep)?;
+                }
+                w.write_char(c)?;
+            }
+        }
+        None => w.write_str(digits)?,
+    }
+    match num[digits_end..].strip_prefix('.') {
+        Some(frac) => {
+            w.write_char(point)?;
+            w.write_str(frac)
+        }
+        None => w.write_str(&num[digits_end..]),
+    }
+}
+
+#[cfg(test)]
+fn grouped(num: &str) -> String {
+    let mut s = String::new();
+    write_grouped(&mut s, num, ',').unwrap();
+    s
+}
+
+#[test]
+fn test_short() {
+    assert_eq!(grouped("0").as_str(), "0");
+    assert_eq!(grouped("532").as_str(), "532");
+}
+
+#[test]
+fn test_thousands() {
+    assert_eq!(grouped("13200").as_str(), "13,200");
+    assert_eq!(grouped("1234567").as_str(), "1,234,567");
+}
+
+#[test]
+fn test_signed_fraction() {
+    assert_eq!(grouped("-1234.5678").as_str(), "-1,234.5678");
+}
+
+#[test]
+fn test_decimal_comma() {
+    let mut s = String::new();
+    write_number(&mut s, "-1234.5678", Some('.'), ',').unwrap();
+    assert_eq!(s.as_str(), "-1.234,5678");
+    let mut s = String::new();
+    write_number(&mut s, "12.89", None, ',').unwrap();
+    assert_eq!(s.as_str(), "12,89");
+}
@@ -0,0 +1,251 @@
+//! Language support for long-form output.
+//!
+//! The long (non-compact) forms of durations spell out their units as words.  The
+//! [Language] trait supplies those words, and [Lang] provides built-in packs for a
+//! few common languages.  Words are chosen by CLDR-style [plural
+//! categories][PluralCategory], so languages with several plural forms (such as
+//! Russian and Polish) are written correctly:
+//!
+//! ```
+//! # use friendly::seconds;
+//! # use friendly::lang::Lang;
+//! let d = seconds(3725.0).compact(false).parts(2).lang(&Lang::De);
+//! assert_eq!(d.to_string().as_str(), "1 Stunde 2 Minuten");
+//! ```
+
+mod plural;
+
+pub use plural::{Operands, PluralCategory};
+
+/// Units of time that are written out in long-form durations.
+///
+/// Units are ordered from largest to smallest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TimeUnit {
+    Year,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Millisecond,
+    Microsecond,
+    Nanosecond,
+}
+
+/// Words for writing quantities in a particular language.
+///
+/// Implement this trait to add a language that is not built in.
+pub trait Language {
+    /// Get the plural category for a formatted number.
+    ///
+    /// The default implementation uses the English rule (“1” is singular, anything
+    /// else is plural).
+    fn plural(&self, ops: &Operands) -> PluralCategory {
+        plural::one_other(ops)
+    }
+
+    /// Get the word for a time unit in a plural category.
+    fn time_unit(&self, unit: TimeUnit, plural: PluralCategory) -> &'static str;
+
+    /// Whether numbers and the words that follow them are separated by spaces.
+    fn spaced(&self) -> bool {
+        true
+    }
+}
+
+/// Built-in language packs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Lang {
+    /// English.
+    #[default]
+    En,
+    /// German.
+    De,
+    /// French.
+    Fr,
+    /// Spanish.
+    Es,
+    /// Portuguese.
+    Pt,
+    /// Japanese.
+    Ja,
+    /// Russian.
+    Ru,
+    /// Polish.
+    Pl,
+}
+
+/// Word forms for a language with only singular and plural.
+const fn two(one: &'static str, other: &'static str) -> [&'static str; 4] {
+    [one, other, other, other]
+}
+
+impl Lang {
+    /// Get a static reference to this language pack.
+    pub(crate) fn as_static(self) -> &'static Lang {
+        match self {
+            Lang::En => &Lang::En,
+            Lang::De => &Lang::De,
+            Lang::Fr => &Lang::Fr,
+            Lang::Es => &Lang::Es,
+            Lang::Pt => &Lang::Pt,
+            Lang::Ja => &Lang::Ja,
+            Lang::Ru => &Lang::Ru,
+            Lang::Pl => &Lang::Pl,
+        }
+    }
+
+    /// Get the forms of a time unit word, as `[one, few, many, other]`.
+    fn time_words(&self, unit: TimeUnit) -> [&'static str; 4] {
+        use TimeUnit::*;
+        match (self, unit) {
+            (Lang::En, Year) => two("year", "years"),
+            (Lang::En, Month) => two("month", "months"),
+            (Lang::En, Week) => two("week", "weeks"),
+            (Lang::En, Day) => two("day", "days"),
+            (Lang::En, Hour) => two("hour", "hours"),
+            (Lang::En, Minute) => two("minute", "minutes"),
+            (Lang::En, Second) => two("second", "seconds"),
+            (Lang::En, Millisecond) => two("millisecond", "milliseconds"),
+            (Lang::En, Microsecond) => two("microsecond", "microseconds"),
+            (Lang::En, Nanosecond) => two("nanosecond", "nanoseconds"),
+            (Lang::De, Year) => two("Jahr", "Jahre"),
+            (Lang::De, Month) => two("Monat", "Monate"),
+            (Lang::De, Week) => two("Woche", "Wochen"),
+            (Lang::De, Day) => two("Tag", "Tage"),
+            (Lang::De, Hour) => two("Stunde", "Stunden"),
+            (Lang::De, Minute) => two("Minute", "Minuten"),
+            (Lang::De, Second) => two("Sekunde", "Sekunden"),
+            (Lang::De, Millisecond) => two("Millisekunde", "Millisekunden"),
+            (Lang::De, Microsecond) => two("Mikrosekunde", "Mikrosekunden"),
+            (Lang::De, Nanosecond) => two("Nanosekunde", "Nanosekunden"),
+            (Lang::Fr, Year) => two("an", "ans"),
+            (Lang::Fr, Month) => two("mois", "mois"),
+            (Lang::Fr, Week) => two("semaine", "semaines"),
+            (Lang::Fr, Day) => two("jour", "jours"),
+            (Lang::Fr, Hour) => two("heure", "heures"),
+            (Lang::Fr, Minute) => two("minute", "minutes"),
+            (Lang::Fr, Second) => two("seconde", "secondes"),
+            (Lang::Fr, Millisecond) => two("milliseconde", "millisecondes"),
+            (Lang::Fr, Microsecond) => two("microseconde", "microsecondes"),
+            (Lang::Fr, Nanosecond) => two("nanoseconde", "nanosecondes"),
+            (Lang::Es, Year) => two("año", "años"),
+            (Lang::Es, Month) => two("mes", "meses"),
+            (Lang::Es, Week) => two("semana", "semanas"),
+            (Lang::Es, Day) => two("día", "días"),
+            (Lang::Es, Hour) => two("hora", "horas"),
+            (Lang::Es, Minute) => two("minuto", "minutos"),
+            (Lang::Es, Second) => two("segundo", "segundos"),
+            (Lang::Es, Millisecond) => two("milisegundo", "milisegundos"),
+            (Lang::Es, Microsecond) => two("microsegundo", "microsegundos"),
+            (Lang::Es, Nanosecond) => two("nanosegundo", "nanosegundos"),
+            (Lang::Pt, Year) => two("ano", "anos"),
+            (Lang::Pt, Month) => two("mês", "meses"),
+            (Lang::Pt, Week) => two("semana", "semanas"),
+            (Lang::Pt, Day) => two("dia", "dias"),
+            (Lang::Pt, Hour) => two("hora", "horas"),
+            (Lang::Pt, Minute) => two("minuto", "minutos"),
+            (Lang::Pt, Second) => two("segundo", "segundos"),
+            (Lang::Pt, Millisecond) => two("milissegundo", "milissegundos"),
+            (Lang::Pt, Microsecond) => two("microssegundo", "microssegundos"),
+            (Lang::Pt, Nanosecond) => two("nanossegundo", "nanossegundos"),
+            (Lang::Ja, Year) => two("年", "年"),
+            (Lang::Ja, Month) => two("か月", "か月"),
+            (Lang::Ja, Week) => two("週間", "週間"),
+            (Lang::Ja, Day) => two("日", "日"),
+            (Lang::Ja, Hour) => two("時間", "時間"),
+            (Lang::Ja, Minute) => two("分", "分"),
+            (Lang::Ja, Second) => two("秒", "秒"),
+            (Lang::Ja, Millisecond) => two("ミリ秒", "ミリ秒"),
+            (Lang::Ja, Microsecond) => two("マイクロ秒", "マイクロ秒"),
+            (Lang::Ja, Nanosecond) => two("ナノ秒", "ナノ秒"),
+            (Lang::Ru, Year) => ["год", "года", "лет", "года"],
+            (Lang::Ru, Month) => ["месяц", "месяца", "месяцев", "месяца"],
+            (Lang::Ru, Week) => ["неделя", "недели", "недель", "недели"],
+            (Lang::Ru, Day) => ["день", "дня", "дней", "дня"],
+            (Lang::Ru, Hour) => ["час", "часа", "часов", "часа"],
+            (Lang::Ru, Minute) => ["минута", "минуты", "минут", "минуты"],
+            (Lang::Ru, Second) => ["секунда", "секунды", "секунд", "секунды"],
+            (Lang::Ru, Millisecond) => [
+                "миллисекунда",
+                "миллисекунды",
+                "миллисекунд",
+                "миллисекунды",
+            ],
+            (Lang::Ru, Microsecond) => [
+                "микросекунда",
+                "микросекунды",
+                "микросекунд",
+                "микросекунды",
+            ],
+            (Lang::Ru, Nanosecond) => ["наносекунда", "наносекунды", "наносекунд", "наносекунды"],
+            (Lang::Pl, Year) => ["rok", "lata", "lat", "roku"],
+            (Lang::Pl, Month) => ["miesiąc", "miesiące", "miesięcy", "miesiąca"],
+            (Lang::Pl, Week) => ["tydzień", "tygodnie", "tygodni", "tygodnia"],
+            (Lang::Pl, Day) => ["dzień", "dni", "dni", "dnia"],
+            (Lang::Pl, Hour) => ["godzina", "godziny", "godzin", "godziny"],
+            (Lang::Pl, Minute) => ["minuta", "minuty", "minut", "minuty"],
+            (Lang::Pl, Second) => ["sekunda", "sekundy", "sekund", "sekundy"],
+            (Lang::Pl, Millisecond) => ["milisekunda", "milisekundy", "milisekund", "milisekundy"],
+            (Lang::Pl, Microsecond) => [
+                "mikrosekunda",
+                "mikrosekundy",
+                "mikrosekund",
+                "mikrosekundy",
+            ],
+            (Lang::Pl, Nanosecond) => ["nanosekunda", "nanosekundy", "nanosekund", "nanosekundy"],
+        }
+    }
+}
+
+impl Language for Lang {
+    fn plural(&self, ops: &Operands) -> PluralCategory {
+        match self {
+            Lang::Fr | Lang::Pt => plural::zero_one_other(ops),
+            Lang::Ja => PluralCategory::Other,
+            Lang::Ru => plural::east_slavic(ops),
+            Lang::Pl => plural::polish(ops),
+            _ => plural::one_other(ops),
+        }
+    }
+
+    fn time_unit(&self, unit: TimeUnit, plural: PluralCategory) -> &'static str {
+        let [one, few, many, other] = self.time_words(unit);
+        match plural {
+            PluralCategory::One => one,
+            PluralCategory::Few => few,
+            PluralCategory::Many => many,
+            _ => other,
+        }
+    }
+
+    fn spaced(&self) -> bool {
+        *self != Lang::Ja
+    }
+}
+
+#[test]
+fn test_english_words() {
+    let hours = |s| Lang::En.time_unit(TimeUnit::Hour, Lang::En.plural(&Operands::of(s)));
+    assert_eq!(hours("1"), "hour");
+    assert_eq!(hours("2"), "hours");
+    assert_eq!(hours("1.00"), "hours");
+}
+
+#[test]
+fn test_japanese_unspaced() {
+    assert!(Lang::De.spaced());
+    assert!(!Lang::Ja.spaced());
+}
+
+#[test]
+fn test_russian_words() {
+    let mins = |s| Lang::Ru.time_unit(TimeUnit::Minute, Lang::Ru.plural(&Operands::of(s)));
+    assert_eq!(mins("1"), "минута");
+    assert_eq!(mins("3"), "минуты");
+    assert_eq!(mins("5"), "минут");
+    assert_eq!(mins("21"), "минута");
+    assert_eq!(mins("2.50"), "минуты");
+}
@@ -0,0 +1,132 @@
+//! CLDR-style plural categories.
+//!
+//! Languages differ in how many plural forms they have and which numbers take each
+//! one; CLDR describes these with a small set of [categories][PluralCategory] chosen by
+//! rules over a number's [Operands].
+
+/// Plural categories, as defined by CLDR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+/// The operands of a formatted number used by plural rules.
+///
+/// Plural rules depend on how a number is written, not just its value: in English,
+/// “1 second” is singular but “1.00 seconds” is not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Operands {
+    /// The integer digits.
+    pub i: u64,
+    /// The number of visible fraction digits.
+    pub v: usize,
+    /// The visible fraction digits, as an integer.
+    pub f: u64,
+}
+
+impl Operands {
+    /// Compute the operands of a number formatted with `.` as its decimal point.
+    ///
+    /// The sign is ignored, and integers too large for a `u64` saturate.
+    pub fn of(num: &str) -> Operands {
+        let num = num.trim_start_matches(['-', '+']);
+        let (int, frac) = num.split_once('.').unwrap_or((num, ""));
+        Operands {
+            i: digits(int),
+            v: frac.len(),
+            f: digits(frac),
+        }
+    }
+}
+
+fn digits(s: &str) -> u64 {
+    s.bytes().filter(|b| b.is_ascii_digit()).fold(0u64, |n, b| {
+        n.saturating_mul(10).saturating_add((b - b'0') as u64)
+    })
+}
+
+/// The plural rule for English, German, Spanish, and similar languages.
+pub(crate) fn one_other(ops: &Operands) -> PluralCategory {
+    if ops.i == 1 && ops.v == 0 {
+        PluralCategory::One
+    } else {
+        PluralCategory::Other
+    }
+}
+
+/// The plural rule for French and Portuguese, where 0 and 1 are singular.
+pub(crate) fn zero_one_other(ops: &Operands) -> PluralCategory {
+    if ops.i <= 1 {
+        PluralCategory::One
+    } else {
+        PluralCategory::Other
+    }
+}
+
+/// The plural rule for Russian (and other East Slavic languages).
+pub(crate) fn east_slavic(ops: &Operands) -> PluralCategory {
+    let (i10, i100) = (ops.i % 10, ops.i % 100);
+    if ops.v > 0 {
+        PluralCategory::Other
+    } else if i10 == 1 && i100 != 11 {
+        PluralCategory::One
+    } else if (2..=4).contains(&i10) && !(12..=14).contains(&i100) {
+        PluralCategory::Few
+    } else {
+        PluralCategory::Many
+    }
+}
+
+/// The plural rule for Polish.
+pub(crate) fn polish(ops: &Operands) -> PluralCategory {
+    let (i10, i100) = (ops.i % 10, ops.i % 100);
+    if ops.v > 0 {
+        PluralCategory::Other
+    } else if ops.i == 1 {
+        PluralCategory::One
+    } else if (2..=4).contains(&i10) && !(12..=14).contains(&i100) {
+        PluralCategory::Few
+    } else {
+        PluralCategory::Many
+    }
+}
+
+#[test]
+fn test_operands() {
+    assert_eq!(Operands::of("1"), Operands { i: 1, v: 0, f: 0 });
+    assert_eq!(Operands::of("-10.50"), Operands { i: 10, v: 2, f: 50 });
+}
+
+#[test]
+fn test_english() {
+    assert_eq!(one_other(&Operands::of("1")), PluralCategory::One);
+    assert_eq!(one_other(&Operands::of("1.00")), PluralCategory::Other);
+    assert_eq!(one_other(&Operands::of("0")), PluralCategory::Other);
+}
+
+#[test]
+fn test_russian() {
+    let cat = |s| east_slavic(&Operands::of(s));
+    assert_eq!(cat("1"), PluralCategory::One);
+    assert_eq!(cat("21"), PluralCategory::One);
+    assert_eq!(cat("11"), PluralCategory::Many);
+    assert_eq!(cat("3"), PluralCategory::Few);
+    assert_eq!(cat("13"), PluralCategory::Many);
+    assert_eq!(cat("25"), PluralCategory::Many);
+    assert_eq!(cat("1.50"), PluralCategory::Other);
+}
+
+#[test]
+fn test_polish() {
+    let cat = |s| polish(&Operands::of(s));
+    assert_eq!(cat("1"), PluralCategory::One);
+    assert_eq!(cat("21"), PluralCategory::Many);
+    assert_eq!(cat("22"), PluralCategory::Few);
+    assert_eq!(cat("12"), PluralCategory::Many);
+    assert_eq!(cat("0.5"), PluralCategory::Other);
+}
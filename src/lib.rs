@@ -17,16 +17,110 @@
 //!
 //! This crate supports some features:
 //!
-//! - `chrono` — enables support for types from the Chrono crate (currently just [chrono::Duration])
+//! - `chrono` — enables support for types from the Chrono crate ([chrono::Duration], and [chrono::DateTime] and [chrono::NaiveDateTime] as timestamps)
+//! - `time` — enables support for types from the `time` crate ([time::Duration], and [time::OffsetDateTime] as timestamps)
+//! - `json` — implements [quantity::QVal] for [serde_json::Number]
+//! - `rust_decimal` — implements [quantity::QVal] for `rust_decimal::Decimal`, with exact rounding
+//! - `num-bigint` — implements [quantity::QVal] for `num_bigint::BigInt` and `BigUint`
+//! - `fast-fmt` — uses integer digit generation (via `itoa`) for faster number formatting
+//! - `serde` — structured, serializable descriptions of quantities, and [serde] helpers for human-readable fields
+//! - `clap` — [clap] value parsers for human-readable arguments
+//! - `tracing` — human-readable [tracing] field values
+//! - `derive` — `#[derive(FriendlyDisplay)]` for human-readable struct display
 
+pub mod align;
+pub mod angle;
+mod ascii;
+pub mod availability;
+pub mod buffer;
+#[cfg(feature = "clap")]
+pub mod clap;
+pub mod config;
+pub mod count;
+pub mod exact;
+pub mod ext;
+pub mod fraction;
+pub mod grouping;
+pub mod lang;
+pub mod natural;
+pub mod notation;
+mod numfmt;
+pub mod opt;
+pub mod ordinal;
+pub mod parse;
+pub mod path;
+pub mod percent;
+pub mod plural;
+pub mod prelude;
+pub mod probability;
+pub mod progress;
 pub mod quantity;
+pub mod range;
+pub mod relative;
 pub mod scale;
+#[cfg(feature = "serde")]
+pub mod serde;
 pub mod sigfig;
+pub mod spacing;
+pub mod temperature;
 pub mod temporal;
+pub mod text;
+pub mod throughput;
+#[cfg(feature = "tracing")]
+pub mod tracing;
 
+pub use angle::angle;
+pub use availability::availability;
+pub use count::count;
+pub use exact::Exact;
+pub use fraction::fraction;
+pub use grouping::Grouping;
+pub use notation::Notation;
+pub use opt::opt;
+pub use ordinal::ordinal;
+pub use path::path;
+pub use percent::{PerUnit, Percent};
+pub use plural::{pluralize, pluralize_irregular};
+pub use probability::odds;
+pub use progress::{eta, progress};
 pub use quantity::Quantity;
+pub use range::range;
 pub use scale::Scale;
-pub use temporal::{duration, seconds};
+pub use spacing::SpaceStyle;
+#[cfg(feature = "chrono")]
+pub use temporal::date;
+pub use temporal::{duration, seconds, time_between};
+pub use text::truncate;
+pub use throughput::Throughput;
+
+/// Derive a human-readable [Display](std::fmt::Display) for status structs (with the
+/// `derive` feature).
+///
+/// ```
+/// # use std::time::Duration;
+/// use friendly::FriendlyDisplay;
+///
+/// #[derive(FriendlyDisplay)]
+/// struct Status {
+///     #[friendly(bytes)]
+///     size: u64,
+///     #[friendly(duration)]
+///     elapsed: Duration,
+///     #[friendly(skip)]
+///     path: String,
+/// }
+///
+/// let st = Status {
+///     size: 1_288_490_189,
+///     elapsed: Duration::from_secs(182),
+///     path: "data.bin".into(),
+/// };
+/// assert_eq!(st.to_string().as_str(), "size: 1.200 GiB, elapsed: 3m2.00s");
+/// ```
+#[cfg(feature = "derive")]
+pub use friendly_derive::FriendlyDisplay;
+
+use std::time::Duration;
 
 use quantity::QVal;
 
@@ -52,12 +146,147 @@ pub fn bytes<V: QVal>(val: V) -> Quantity<V, scale::Binary> {
     Quantity::binary(val).suffix("B").integral(true)
 }
 
+/// Display an exact number of bytes.
+///
+/// This is the exact counterpart to [bytes]: the full count is written with grouped
+/// digits and a unit word that agrees with it.
+///
+/// ```
+/// # use friendly::bytes_exact;
+/// assert_eq!(bytes_exact(13_200).to_string().as_str(), "13,200 bytes");
+/// assert_eq!(bytes_exact(1).to_string().as_str(), "1 byte");
+/// ```
+pub fn bytes_exact<V: QVal>(val: V) -> Exact<V> {
+    Exact::new(val).units("byte", "bytes")
+}
+
+/// Display a number of bits.
+///
+/// Unlike [bytes], this follows networking conventions and uses decimal prefixes
+/// with a lowercase “b”:
+///
+/// ```
+/// # use friendly::bits;
+/// assert_eq!(bits(100_000_000).to_string().as_str(), "100.0 Mb");
+/// ```
+pub fn bits<V: QVal>(val: V) -> Quantity<V, scale::Decimal> {
+    Quantity::decimal(val).suffix("b").integral(true)
+}
+
+/// Display a number of bytes as bits.
+///
+/// ```
+/// # use friendly::bytes_to_bits;
+/// assert_eq!(bytes_to_bits(12_500_000).to_string().as_str(), "100.0 Mb");
+/// ```
+pub fn bytes_to_bits<V: QVal>(bytes: V) -> Quantity<f64, scale::Decimal> {
+    bits(bytes.as_float() * 8.0)
+}
+
+/// Display a transfer rate in bytes per unit time.
+///
+/// The rate is shown per second, unless it is below 1 byte per second, in which case
+/// it is shown per minute or per hour:
+///
+/// ```
+/// # use std::time::Duration;
+/// # use friendly::rate;
+/// let r = rate(10_066_329, Duration::from_secs(3));
+/// assert_eq!(r.to_string().as_str(), "3.200 MiB/s");
+/// let r = rate(90, Duration::from_secs(3600));
+/// assert_eq!(r.to_string().as_str(), "1.500 B/min");
+/// let r = rate(30, Duration::from_secs(86400));
+/// assert_eq!(r.to_string().as_str(), "1.250 B/h");
+/// ```
+pub fn rate<V: QVal>(bytes: V, elapsed: Duration) -> Quantity<f64, scale::Binary> {
+    let per_sec = bytes.as_float() / elapsed.as_secs_f64();
+    let (val, sfx) = if per_sec.abs() >= 1.0 || per_sec == 0.0 || !per_sec.is_finite() {
+        (per_sec, "B/s")
+    } else if per_sec.abs() * 60.0 >= 1.0 {
+        (per_sec * 60.0, "B/min")
+    } else {
+        (per_sec * 3600.0, "B/h")
+    };
+    Quantity::binary(val).suffix(sfx)
+}
+
+/// Display a frequency in hertz.
+///
+/// ```
+/// # use friendly::hertz;
+/// assert_eq!(hertz(3.6e9).to_string().as_str(), "3.600 GHz");
+/// ```
+pub fn hertz<V: QVal>(val: V) -> Quantity<V, scale::Decimal> {
+    Quantity::decimal(val).suffix("Hz")
+}
+
+/// Display the frequency corresponding to a period.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use friendly::frequency;
+/// let f = frequency(Duration::from_micros(20));
+/// assert_eq!(f.to_string().as_str(), "50.00 kHz");
+/// ```
+pub fn frequency(period: Duration) -> Quantity<f64, scale::Decimal> {
+    hertz(1.0 / period.as_secs_f64())
+}
+
+/// Display the period corresponding to a frequency in hertz.
+///
+/// ```
+/// # use friendly::period;
+/// assert_eq!(period(3.6e9).to_string().as_str(), "277.8 ps");
+/// ```
+pub fn period(hz: f64) -> Quantity<f64, scale::Decimal> {
+    Quantity::decimal(1.0 / hz).suffix("s")
+}
+
 /// An ordinary auto-scaled value.
+///
+/// Values of integer types that do not need a prefix are displayed exactly:
+///
+/// ```
+/// # use friendly::scalar;
+/// assert_eq!(scalar(0).to_string().as_str(), "0");
+/// assert_eq!(scalar(0.0).to_string().as_str(), "0.0000");
+/// assert_eq!(scalar(15_250).to_string().as_str(), "15.25 k");
+/// ```
 pub fn scalar<V: QVal>(val: V) -> Quantity<V, scale::Decimal> {
-    Quantity::decimal(val)
+    Quantity::decimal(val).auto_integral(true)
 }
 
 /// An ordinary auto-scaled integer value.
 pub fn integer<V: QVal>(val: V) -> Quantity<V, scale::Decimal> {
     Quantity::decimal(val).integral(true)
 }
+
+/// Display a fraction as a percentage.
+///
+/// ```
+/// # use friendly::percent;
+/// assert_eq!(percent(0.4237).to_string().as_str(), "42.37%");
+/// ```
+pub fn percent(frac: f64) -> Percent {
+    Percent::new(frac)
+}
+
+/// Display a fraction per-mille (“‰”).
+///
+/// ```
+/// # use friendly::permille;
+/// assert_eq!(permille(0.0042).to_string().as_str(), "4.2‰");
+/// ```
+pub fn permille(frac: f64) -> Percent {
+    Percent::new(frac).permille()
+}
+
+/// Display a fraction in basis points (“bp”).
+///
+/// ```
+/// # use friendly::basis_points;
+/// assert_eq!(basis_points(0.0037).to_string().as_str(), "37 bp");
+/// ```
+pub fn basis_points(frac: f64) -> Percent {
+    Percent::new(frac).basis_points()
+}
@@ -0,0 +1,228 @@
+//! Natural ordering of strings containing friendly quantities.
+//!
+//! This module compares strings by parsing the quantities embedded in them, so that
+//! already-formatted values sort by magnitude rather than by character:
+//!
+//! ```
+//! # use friendly::natural;
+//! let mut sizes = vec!["1.2 GiB", "9.8 MiB", "512 B"];
+//! natural::sort(&mut sizes);
+//! assert_eq!(sizes, vec!["512 B", "9.8 MiB", "1.2 GiB"]);
+//!
+//! let mut times = vec!["1h", "2m3s", "324.0ms"];
+//! natural::sort(&mut times);
+//! assert_eq!(times, vec!["324.0ms", "2m3s", "1h"]);
+//! ```
+//!
+//! Quantities are compared by value when they have the same unit (after removing
+//! binary or decimal prefixes); durations, in either compact or long form, are all
+//! compared in seconds.  Text between quantities is compared as-is.
+use std::cmp::Ordering;
+
+use crate::parse::{scan_number, TIME_UNITS};
+use crate::scale::{Binary, Decimal, Prefix};
+
+/// A token in a string being compared.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token<'a> {
+    /// Text that is not part of a quantity.
+    Text(&'a str),
+    /// A quantity in base units, with its unit.
+    Value(f64, &'a str),
+}
+
+/// A number with its (possibly empty) unit word, and the position after the unit.
+struct RawQuantity<'a> {
+    num: f64,
+    unit: &'a str,
+    end: usize,
+}
+
+/// Try to read a number and following unit word starting at `pos`.
+fn read_quantity(s: &str, pos: usize) -> Option<RawQuantity<'_>> {
+    let (num, end) = scan_number(s, pos, '.', Some(','))?;
+    let rest = &s[end..];
+    let unit_start = if rest.starts_with(' ') { end + 1 } else { end };
+    let unit_len: usize = s[unit_start..]
+        .chars()
+        .take_while(|c| c.is_alphabetic() || *c == '/')
+        .map(char::len_utf8)
+        .sum();
+    if unit_len == 0 {
+        Some(RawQuantity { num, unit: "", end })
+    } else {
+        Some(RawQuantity {
+            num,
+            unit: &s[unit_start..unit_start + unit_len],
+            end: unit_start + unit_len,
+        })
+    }
+}
+
+/// Get the length of a duration unit in seconds.
+fn time_unit(unit: &str) -> Option<f64> {
+    TIME_UNITS
+        .iter()
+        .find(|(u, _)| *u == unit)
+        .map(|(_, nanos)| *nanos as f64 * 1e-9)
+}
+
+/// Split a unit into its prefix multiplier and base unit.
+fn split_prefix(unit: &str) -> (f64, &str) {
+    let bin = Binary::ALL_PREFIXES
+        .iter()
+        .map(|p| (p.label(), p.multiplier()));
+    let dec = Decimal::ALL_PREFIXES
+        .iter()
+        .map(|p| (p.label(), p.multiplier()));
+    for (label, mult) in bin.chain(dec) {
+        if label.is_empty() {
+            continue;
+        }
+        if let Some(base) = unit.strip_prefix(label) {
+            // long lowercase words are words, not prefixed units
+            if base.chars().count() <= 3 || base.contains('/') {
+                return (mult, base);
+            }
+        }
+    }
+    (1.0, unit)
+}
+
+/// Split a string into tokens.
+fn tokenize(s: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut text_start = 0;
+    let mut pos = 0;
+    while pos < s.len() {
+        // signs only start numbers at the beginning of a word
+        let c = s[pos..].chars().next().unwrap();
+        let at_word = pos == 0 || s[..pos].ends_with(char::is_whitespace);
+        let starts = c.is_ascii_digit() || ((c == '-' || c == '+') && at_word);
+        let q = if starts { read_quantity(s, pos) } else { None };
+        let Some(q) = q else {
+            pos += c.len_utf8();
+            continue;
+        };
+
+        if text_start < pos {
+            tokens.push(Token::Text(&s[text_start..pos]));
+        }
+        if let Some(mut secs) = time_unit(q.unit).map(|u| q.num * u) {
+            // durations may have several parts
+            let mut end = q.end;
+            loop {
+                let next = if s[end..].starts_with(' ') {
+                    end + 1
+                } else {
+                    end
+                };
+                match read_quantity(s, next) {
+                    Some(nq) if next < s.len() && time_unit(nq.unit).is_some() => {
+                        secs += nq.num * time_unit(nq.unit).unwrap();
+                        end = nq.end;
+                    }
+                    _ => break,
+                }
+            }
+            tokens.push(Token::Value(secs, "s"));
+            pos = end;
+        } else {
+            let (mult, unit) = split_prefix(q.unit);
+            tokens.push(Token::Value(q.num * mult, unit));
+            pos = q.end;
+        }
+        text_start = pos;
+    }
+    if text_start < s.len() {
+        tokens.push(Token::Text(&s[text_start..]));
+    }
+    tokens
+}
+
+fn compare_tokens(a: &Token<'_>, b: &Token<'_>) -> Ordering {
+    match (a, b) {
+        (Token::Value(av, au), Token::Value(bv, bu)) => au.cmp(bu).then(av.total_cmp(bv)),
+        (Token::Value(..), Token::Text(..)) => Ordering::Less,
+        (Token::Text(..), Token::Value(..)) => Ordering::Greater,
+        (Token::Text(at), Token::Text(bt)) => at.cmp(bt),
+    }
+}
+
+/// Compare two strings in natural order.
+///
+/// Strings that compare equal by value (e.g. “1 KiB” and “1024 B”) are ordered by
+/// their text, so this is a total order suitable for sorting.
+pub fn compare(a: &str, b: &str) -> Ordering {
+    let at = tokenize(a);
+    let bt = tokenize(b);
+    at.iter()
+        .zip(bt.iter())
+        .map(|(a, b)| compare_tokens(a, b))
+        .find(|o| o.is_ne())
+        .unwrap_or_else(|| at.len().cmp(&bt.len()))
+        .then_with(|| a.cmp(b))
+}
+
+/// Sort strings in natural order.
+pub fn sort<S: AsRef<str>>(items: &mut [S]) {
+    items.sort_by(|a, b| compare(a.as_ref(), b.as_ref()))
+}
+
+#[test]
+fn test_plain_numbers() {
+    let mut files = vec!["file10.txt", "file2.txt", "file1.txt"];
+    sort(&mut files);
+    assert_eq!(files, vec!["file1.txt", "file2.txt", "file10.txt"]);
+}
+
+#[test]
+fn test_decimal_prefixes() {
+    assert_eq!(compare("950 kB", "1.2 MB"), Ordering::Less);
+    assert_eq!(compare("15.25 MW", "900 kW"), Ordering::Greater);
+}
+
+#[test]
+fn test_rates() {
+    assert_eq!(compare("178.1 KiB/s", "1.0 MiB/s"), Ordering::Less);
+}
+
+#[test]
+fn test_long_durations() {
+    let a = "5 hours 32 minutes 10.50 seconds";
+    let b = "1 days 2 hours";
+    assert_eq!(compare(a, b), Ordering::Less);
+}
+
+#[test]
+fn test_milliseconds_minutes() {
+    assert_eq!(compare("500 ms", "2s"), Ordering::Less);
+    assert_eq!(compare("2m", "90s"), Ordering::Greater);
+}
+
+#[test]
+fn test_grouped() {
+    assert_eq!(compare("13,200 bytes", "9,999 bytes"), Ordering::Greater);
+}
+
+#[test]
+fn test_negative() {
+    assert_eq!(compare("-1.2 MiB", "300 KiB"), Ordering::Less);
+}
+
+#[test]
+fn test_equal_values() {
+    assert_eq!(compare("1 KiB", "1024 B"), Ordering::Less);
+    assert_eq!(compare("1 KiB", "1 KiB"), Ordering::Equal);
+}
+
+#[test]
+fn test_words_not_prefixed() {
+    assert_eq!(tokenize("3 files"), vec![Token::Value(3.0, "files")]);
+}
+
+#[test]
+fn test_months_years() {
+    assert_eq!(compare("11mo2d", "1y"), Ordering::Less);
+    assert_eq!(compare("2 hrs", "90 mins"), Ordering::Greater);
+}
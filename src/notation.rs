@@ -0,0 +1,133 @@
+//! Exponent notations for quantities.
+use std::fmt;
+
+#[cfg(test)]
+use crate::sigfig::sigscale;
+
+const SUPERSCRIPT: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+/// How to express the magnitude of a [Quantity](crate::Quantity).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Notation {
+    /// Scale with a unit prefix (“13.20 kB”).
+    #[default]
+    Prefix,
+    /// Scientific notation (“1.320 × 10⁴ B”).
+    Scientific,
+    /// Engineering notation, with exponents that are multiples of 3
+    /// (“13.20 × 10³ B”).
+    Engineering,
+}
+
+impl Notation {
+    /// Split a value into a mantissa, its precision, and an exponent, rounding the
+    /// mantissa with `round` (such as [sigscale](crate::sigfig::sigscale)).  Returns
+    /// `None` for prefix notation.
+    pub(crate) fn split<R>(&self, val: f64, round: R) -> Option<(f64, usize, i32)>
+    where
+        R: Fn(f64) -> (f64, usize),
+    {
+        match self {
+            Notation::Prefix => None,
+            Notation::Scientific => Some(split_exponent(val, round, 1)),
+            Notation::Engineering => Some(split_exponent(val, round, 3)),
+        }
+    }
+}
+
+/// Split a value with an exponent that is a multiple of `step`.
+fn split_exponent<R>(val: f64, round: R, step: i32) -> (f64, usize, i32)
+where
+    R: Fn(f64) -> (f64, usize),
+{
+    if !val.is_normal() {
+        let (m, prec) = round(val);
+        return (m, prec, 0);
+    }
+    let mut exp = (val.abs().log10().floor() as i32).div_euclid(step) * step;
+    let (mut m, mut prec) = round(val / 10f64.powi(exp));
+    // rounding may carry into the next power
+    let limit = 10f64.powi(step);
+    if m.abs() >= limit {
+        exp += step;
+        (m, prec) = round(m / limit);
+    }
+    (m, prec, exp)
+}
+
+/// Write a power-of-10 exponent, either as “ × 10⁴” or (in ASCII) as “e4”.
+pub(crate) fn write_exponent<W: fmt::Write>(w: &mut W, exp: i32, ascii: bool) -> fmt::Result {
+    if ascii {
+        write!(w, "e{}", exp)
+    } else {
+        w.write_str(" × 10")?;
+        write_superscript(w, exp)
+    }
+}
+
+/// Write a unit's power, either as a superscript (“²”) or (in ASCII) as “^2”.
+pub(crate) fn write_power<W: fmt::Write>(w: &mut W, pow: i32, ascii: bool) -> fmt::Result {
+    if ascii {
+        write!(w, "^{}", pow)
+    } else {
+        write_superscript(w, pow)
+    }
+}
+
+/// Write an integer in superscript digits.
+fn write_superscript<W: fmt::Write>(w: &mut W, n: i32) -> fmt::Result {
+    if n < 0 {
+        w.write_char('⁻')?;
+    }
+    for c in n.unsigned_abs().to_string().bytes() {
+        w.write_char(SUPERSCRIPT[(c - b'0') as usize])?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_split_scientific() {
+    assert_eq!(
+        Notation::Scientific.split(13200.0, |v| sigscale(v, 4)),
+        Some((1.32, 3, 4))
+    );
+    assert_eq!(
+        Notation::Scientific.split(-0.00042, |v| sigscale(v, 2)),
+        Some((-4.2, 1, -4))
+    );
+    assert_eq!(Notation::Prefix.split(13200.0, |v| sigscale(v, 4)), None);
+}
+
+#[test]
+fn test_split_carry() {
+    assert_eq!(
+        Notation::Scientific.split(99999.0, |v| sigscale(v, 3)),
+        Some((1.0, 2, 5))
+    );
+}
+
+#[test]
+fn test_split_engineering() {
+    assert_eq!(
+        Notation::Engineering.split(13200.0, |v| sigscale(v, 4)),
+        Some((13.2, 2, 3))
+    );
+    assert_eq!(
+        Notation::Engineering.split(0.00042, |v| sigscale(v, 2)),
+        Some((420.0, 0, -6))
+    );
+    assert_eq!(
+        Notation::Engineering.split(999_990.0, |v| sigscale(v, 4)),
+        Some((1.0, 3, 6))
+    );
+}
+
+#[test]
+fn test_write_exponent() {
+    let mut s = String::new();
+    write_exponent(&mut s, -12, false).unwrap();
+    assert_eq!(s.as_str(), " × 10⁻¹²");
+    let mut s = String::new();
+    write_exponent(&mut s, 4, true).unwrap();
+    assert_eq!(s.as_str(), "e4");
+}
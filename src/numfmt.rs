@@ -0,0 +1,131 @@
+//! Number formatting routines.
+use std::fmt::{self, Write};
+
+use crate::buffer::StackBuf;
+
+/// Powers of 10 for the fast formatting path.
+#[cfg(feature = "fast-fmt")]
+const POW10: [f64; 16] = [
+    1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15,
+];
+
+/// Largest integer that is exactly representable as an `f64`.
+#[cfg(feature = "fast-fmt")]
+const MAX_EXACT: f64 = (1u64 << 53) as f64;
+
+/// Write a number with a fixed number of digits after the decimal point.
+///
+/// This produces the same output as `{:.*}`.  With the `fast-fmt` feature, values
+/// that have already been rounded to `prec` digits (e.g. by [crate::sigfig::sigscale])
+/// are written with integer digit generation instead of the general float formatter.
+/// Other values fall back to the float formatter, because rounding them here could
+/// resolve ties differently (0.125 is really 0.125, so `{:.2}` writes “0.12”).
+pub(crate) fn write_fixed<W: fmt::Write>(out: &mut W, val: f64, prec: usize) -> fmt::Result {
+    #[cfg(feature = "fast-fmt")]
+    if val.is_finite() && prec < POW10.len() {
+        let scaled = (val.abs() * POW10[prec]).round();
+        if scaled < MAX_EXACT && scaled / POW10[prec] == val.abs() {
+            let mut ib = itoa::Buffer::new();
+            return write_digits(out, val.is_sign_negative(), ib.format(scaled as u64), prec);
+        }
+    }
+    write!(out, "{:.*}", prec, val)
+}
+
+/// Get the precision needed to write a number without trailing zeros after the
+/// decimal point, when it is written with at most `prec` digits.
+pub(crate) fn trimmed_precision(val: f64, prec: usize) -> usize {
+    let mut buf = StackBuf::new();
+    if prec == 0 || write_fixed(&mut buf, val, prec).is_err() {
+        return prec;
+    }
+    let zeros = buf
+        .as_str()
+        .bytes()
+        .rev()
+        .take_while(|b| *b == b'0')
+        .count();
+    prec - zeros.min(prec)
+}
+
+/// Write an integer, scaled by 10^prec, as a decimal with `prec` digits after the
+/// point.
+pub(crate) fn write_int_fixed<W: fmt::Write>(
+    out: &mut W,
+    neg: bool,
+    n: u128,
+    prec: usize,
+) -> fmt::Result {
+    let mut buf = StackBuf::new();
+    write!(buf, "{}", n)?;
+    write_digits(out, neg, buf.as_str(), prec)
+}
+
+/// Write a string of digits as a decimal with `prec` digits after the point.
+fn write_digits<W: fmt::Write>(out: &mut W, neg: bool, digits: &str, prec: usize) -> fmt::Result {
+    if neg {
+        out.write_char('-')?;
+    }
+    if prec == 0 {
+        out.write_str(digits)
+    } else if digits.len() > prec {
+        let (int, frac) = digits.split_at(digits.len() - prec);
+        out.write_str(int)?;
+        out.write_char('.')?;
+        out.write_str(frac)
+    } else {
+        out.write_str("0.")?;
+        for _ in digits.len()..prec {
+            out.write_char('0')?;
+        }
+        out.write_str(digits)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::write_fixed;
+    use crate::sigfig::sigscale;
+    use proptest::prelude::*;
+
+    fn fixed(val: f64, prec: usize) -> String {
+        let mut s = String::new();
+        write_fixed(&mut s, val, prec).unwrap();
+        s
+    }
+
+    #[test]
+    fn test_small() {
+        assert_eq!(fixed(0.00032, 5).as_str(), "0.00032");
+    }
+
+    #[test]
+    fn test_negative() {
+        assert_eq!(fixed(-15.32, 2).as_str(), "-15.32");
+    }
+
+    #[test]
+    fn test_integer() {
+        assert_eq!(fixed(123500.0, 0).as_str(), "123500");
+    }
+
+    #[test]
+    fn test_ties() {
+        assert_eq!(fixed(0.125, 2).as_str(), "0.12");
+        assert_eq!(fixed(2.5, 0).as_str(), "2");
+        assert_eq!(fixed(-0.375, 2).as_str(), "-0.38");
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_std(v in -1e20f64..1e20, sf in 1usize..10) {
+            let (sv, prec) = sigscale(v, sf);
+            assert_eq!(fixed(sv, prec), format!("{:.*}", prec, sv));
+        }
+
+        #[test]
+        fn test_unrounded_matches_std(v in -1e9f64..1e9, prec in 0usize..8) {
+            assert_eq!(fixed(v, prec), format!("{:.*}", prec, v));
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! Display of optional values.
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::buffer::pad;
+
+/// An optional value, displayed as the value or a placeholder if it is missing.
+///
+/// The value can be any displayable type, such as a humanized quantity:
+///
+/// ```
+/// # use friendly::{bytes, opt};
+/// let sample: Option<u64> = Some(13200);
+/// assert_eq!(opt(sample.map(bytes), "n/a").to_string().as_str(), "12.89 KiB");
+/// let missing: Option<u64> = None;
+/// assert_eq!(opt(missing.map(bytes), "n/a").to_string().as_str(), "n/a");
+/// ```
+///
+/// Padding and alignment apply to either the value or the placeholder.
+#[derive(Debug, Clone)]
+pub struct Opt<T> {
+    value: Option<T>,
+    placeholder: Cow<'static, str>,
+}
+
+/// Display an optional value, with a placeholder if it is missing.
+pub fn opt<T, P>(value: Option<T>, placeholder: P) -> Opt<T>
+where
+    T: fmt::Display,
+    P: Into<Cow<'static, str>>,
+{
+    Opt {
+        value,
+        placeholder: placeholder.into(),
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Opt<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            Some(v) => v.fmt(f),
+            None => pad(f, &self.placeholder),
+        }
+    }
+}
+
+#[test]
+fn test_padding() {
+    use crate::scalar;
+    let o = opt(Some(scalar(2.5).suffix("W")), "—");
+    assert_eq!(format!("[{:>8}]", o).as_str(), "[ 2.500 W]");
+    let o = opt(None::<u64>, "—");
+    assert_eq!(format!("[{:>8}]", o).as_str(), "[       —]");
+}
+
+#[test]
+fn test_precision() {
+    // the precision does not truncate the placeholder
+    let o = opt(None::<u64>, String::from("n/a"));
+    assert_eq!(format!("[{:>5.1}]", o).as_str(), "[  n/a]");
+}
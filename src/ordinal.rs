@@ -0,0 +1,54 @@
+//! Display of ordinal numbers.
+use std::fmt;
+
+/// An ordinal number (“1st”, “22nd”, “113th”).
+///
+/// ```
+/// # use friendly::ordinal;
+/// assert_eq!(ordinal(1).to_string().as_str(), "1st");
+/// assert_eq!(ordinal(22).to_string().as_str(), "22nd");
+/// assert_eq!(ordinal(113).to_string().as_str(), "113th");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ordinal {
+    value: i64,
+}
+
+/// Display an ordinal number.
+pub fn ordinal(value: i64) -> Ordinal {
+    Ordinal { value }
+}
+
+/// Get the English ordinal suffix for a number.
+pub(crate) fn ordinal_suffix(n: i64) -> &'static str {
+    let n = n.unsigned_abs();
+    match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+impl fmt::Display for Ordinal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.value, ordinal_suffix(self.value))
+    }
+}
+
+#[test]
+fn test_teens() {
+    assert_eq!(ordinal(11).to_string().as_str(), "11th");
+    assert_eq!(ordinal(12).to_string().as_str(), "12th");
+    assert_eq!(ordinal(13).to_string().as_str(), "13th");
+    assert_eq!(ordinal(111).to_string().as_str(), "111th");
+}
+
+#[test]
+fn test_others() {
+    assert_eq!(ordinal(0).to_string().as_str(), "0th");
+    assert_eq!(ordinal(3).to_string().as_str(), "3rd");
+    assert_eq!(ordinal(-2).to_string().as_str(), "-2nd");
+    assert_eq!(ordinal(101).to_string().as_str(), "101st");
+}
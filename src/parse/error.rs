@@ -0,0 +1,108 @@
+//! Parse errors.
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+/// The kinds of errors that can occur when parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input was empty.
+    Empty,
+    /// The input did not have a valid number where one was expected.
+    InvalidNumber,
+    /// The unit (with its prefix) was not recognized.
+    UnknownUnit,
+    /// The value is out of range for the result type.
+    OutOfRange,
+}
+
+/// Errors from parsing human-formatted values.
+///
+/// Errors record the span (byte range) of the input that caused the error, and
+/// where possible, the tokens that would have been accepted there:
+///
+/// ```
+/// # use friendly::parse::*;
+/// let err = parse_bytes("12 parsecs").unwrap_err();
+/// assert_eq!(err.kind(), ParseErrorKind::UnknownUnit);
+/// assert_eq!(err.span(), 3..10);
+/// assert_eq!(err.found(), "parsecs");
+/// assert!(err.expected().iter().any(|e| e == "KiB"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    span: Range<usize>,
+    found: String,
+    expected: Vec<String>,
+}
+
+impl ParseError {
+    /// Create a new parse error for a span of the input.
+    pub(crate) fn new(kind: ParseErrorKind, input: &str, span: Range<usize>) -> ParseError {
+        ParseError {
+            kind,
+            found: input[span.clone()].to_string(),
+            span,
+            expected: Vec::new(),
+        }
+    }
+
+    /// Set the tokens that were expected.
+    pub(crate) fn expecting<S: Into<String>, I: IntoIterator<Item = S>>(
+        self,
+        expected: I,
+    ) -> ParseError {
+        ParseError {
+            expected: expected.into_iter().map(Into::into).collect(),
+            ..self
+        }
+    }
+
+    /// Get the kind of error.
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Get the byte range of the input where the error occurred.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Get the input text where the error occurred.
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+
+    /// Get the tokens that would have been accepted (may be empty).
+    pub fn expected(&self) -> &[String] {
+        &self.expected
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::Empty => f.write_str("empty input")?,
+            ParseErrorKind::InvalidNumber => write!(f, "invalid number “{}”", self.found)?,
+            ParseErrorKind::UnknownUnit => write!(f, "unknown unit “{}”", self.found)?,
+            ParseErrorKind::OutOfRange => write!(f, "value “{}” out of range", self.found)?,
+        }
+        write!(f, " at {}..{}", self.span.start, self.span.end)?;
+        if !self.expected.is_empty() {
+            write!(f, " (expected {})", self.expected.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+#[test]
+fn test_display() {
+    let err = ParseError::new(ParseErrorKind::UnknownUnit, "5 q", 2..3).expecting(["k", "M"]);
+    assert_eq!(
+        err.to_string().as_str(),
+        "unknown unit “q” at 2..3 (expected k, M)"
+    );
+}
@@ -0,0 +1,393 @@
+//! Parse human-formatted values back into numbers.
+//!
+//! The parsers in this module accept the formats produced by this crate's display
+//! types, so values can be round-tripped (e.g. through configuration files):
+//!
+//! ```
+//! # use friendly::parse::parse_bytes;
+//! assert_eq!(parse_bytes("12.89 KiB"), Ok(13199));
+//! assert_eq!(parse_bytes("1.5 MB"), Ok(1_500_000));
+//! ```
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::scale::{Binary, Decimal};
+use crate::temporal::{HumanDuration, MONTH_NANOS, YEAR_NANOS};
+
+mod error;
+mod parser;
+
+pub use error::{ParseError, ParseErrorKind};
+pub(crate) use parser::scan_number;
+pub use parser::{Parser, Strictness};
+
+/// Nanoseconds per second.
+const SEC: i128 = 1_000_000_000;
+
+/// Duration units and their lengths in nanoseconds (months and years are average
+/// lengths, as [HumanDuration] writes them).
+pub(crate) const TIME_UNITS: &[(&str, i128)] = &[
+    ("ns", 1),
+    ("μs", 1_000),
+    ("µs", 1_000),
+    ("us", 1_000),
+    ("ms", 1_000_000),
+    ("s", SEC),
+    ("sec", SEC),
+    ("secs", SEC),
+    ("second", SEC),
+    ("seconds", SEC),
+    ("m", 60 * SEC),
+    ("min", 60 * SEC),
+    ("mins", 60 * SEC),
+    ("minute", 60 * SEC),
+    ("minutes", 60 * SEC),
+    ("h", 3600 * SEC),
+    ("hr", 3600 * SEC),
+    ("hrs", 3600 * SEC),
+    ("hour", 3600 * SEC),
+    ("hours", 3600 * SEC),
+    ("d", 86_400 * SEC),
+    ("day", 86_400 * SEC),
+    ("days", 86_400 * SEC),
+    ("w", 604_800 * SEC),
+    ("wk", 604_800 * SEC),
+    ("week", 604_800 * SEC),
+    ("weeks", 604_800 * SEC),
+    ("mo", MONTH_NANOS),
+    ("month", MONTH_NANOS),
+    ("months", MONTH_NANOS),
+    ("y", YEAR_NANOS),
+    ("yr", YEAR_NANOS),
+    ("yrs", YEAR_NANOS),
+    ("year", YEAR_NANOS),
+    ("years", YEAR_NANOS),
+];
+
+/// Get the parser for byte sizes.
+fn bytes_parser() -> Parser {
+    Parser::new()
+        .family::<Binary>()
+        .family::<Decimal>()
+        .fractional_prefixes(false)
+        .suffix("B")
+        .alias("byte")
+        .alias("bytes")
+}
+
+/// Parse a human-formatted size in bytes.
+///
+/// This accepts binary (“KiB”, “MiB”) and decimal (“kB”, “MB”) prefixes, and the
+/// unit may be written as “B”, “byte”, or “bytes” (or omitted, for plain counts).
+/// Fractional results are rounded to the nearest byte.
+pub fn parse_bytes(input: &str) -> Result<u64, ParseError> {
+    bytes_parser().parse_u64(input)
+}
+
+/// Parse a number with an optional SI (decimal) prefix, such as “3.2M” or “15 n”.
+///
+/// This understands the full [Decimal] prefix table; the micro prefix may be written
+/// as “μ”, “µ”, or “u”.
+///
+/// ```
+/// # use friendly::parse::parse_scalar;
+/// assert_eq!(parse_scalar("3.2M"), Ok(3_200_000.0));
+/// assert_eq!(parse_scalar("23.48 m"), Ok(0.02348));
+/// ```
+pub fn parse_scalar(input: &str) -> Result<f64, ParseError> {
+    Parser::new().family::<Decimal>().parse(input)
+}
+
+/// Parse an integer with an optional SI (decimal) prefix, such as “12k”.
+///
+/// This accepts the same input as [parse_scalar], rounding the result to the
+/// nearest integer.
+pub fn parse_integer(input: &str) -> Result<i64, ParseError> {
+    Parser::new().family::<Decimal>().parse_i64(input)
+}
+
+/// Parse a human-formatted duration.
+///
+/// This accepts both the compact (“5h32m10.5s”) and long (“5 hours 32 minutes”)
+/// forms produced by [HumanDuration], including sub-second units (“324.0ms”).  A
+/// bare number is interpreted as seconds.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use friendly::parse::parse_duration;
+/// assert_eq!(parse_duration("5h32m10.5s"), Ok(Duration::from_millis(19_930_500)));
+/// assert_eq!(parse_duration("5 hours 32 minutes"), Ok(Duration::from_secs(19_920)));
+/// ```
+pub fn parse_duration(input: &str) -> Result<Duration, ParseError> {
+    let nanos = parse_nanos(input)?;
+    if nanos < 0 || nanos > Duration::MAX.as_nanos() as i128 {
+        let start = input.len() - input.trim_start().len();
+        let end = input.trim_end().len();
+        Err(ParseError::new(
+            ParseErrorKind::OutOfRange,
+            input,
+            start..end,
+        ))
+    } else {
+        Ok(Duration::new((nanos / SEC) as u64, (nanos % SEC) as u32))
+    }
+}
+
+/// Parse a duration into (rounded, signed) nanoseconds.
+///
+/// A leading sign applies to the whole duration (“-1m30s” is -90 seconds).
+fn parse_nanos(input: &str) -> Result<i128, ParseError> {
+    let end = input.trim_end().len();
+    let mut pos = end - input[..end].trim_start().len();
+    if pos == end {
+        return Err(ParseError::new(
+            ParseErrorKind::Empty,
+            input,
+            0..input.len(),
+        ));
+    }
+    let numbers = Parser::new();
+    let input = &input[..end];
+    let sign = if input[pos..].starts_with('-') {
+        pos += 1;
+        -1
+    } else {
+        1
+    };
+    let out_of_range = |span| ParseError::new(ParseErrorKind::OutOfRange, input, span);
+    if let Ok((num, num_end)) = numbers.read_number(input, pos) {
+        if num_end == end && num >= 0.0 {
+            let nanos = unit_nanos(num, SEC).ok_or_else(|| out_of_range(pos..end))?;
+            return Ok(sign * nanos);
+        }
+    }
+
+    let mut nanos: i128 = 0;
+    while pos < end {
+        let (num, num_end) = numbers.read_number(input, pos)?;
+        if num < 0.0 {
+            // only the whole duration has a sign
+            return Err(ParseError::new(
+                ParseErrorKind::InvalidNumber,
+                input,
+                pos..num_end,
+            ));
+        }
+        let unit_start = end - input[num_end..].trim_start().len();
+        let unit_len = input[unit_start..]
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(end - unit_start);
+        let unit_end = unit_start + unit_len;
+        let unit = &input[unit_start..unit_end];
+        let (_, mult) = TIME_UNITS.iter().find(|(u, _)| *u == unit).ok_or_else(|| {
+            ParseError::new(ParseErrorKind::UnknownUnit, input, unit_start..unit_end)
+                .expecting(TIME_UNITS.iter().map(|(u, _)| *u))
+        })?;
+        nanos = unit_nanos(num, *mult)
+            .and_then(|n| nanos.checked_add(n))
+            .ok_or_else(|| out_of_range(pos..unit_end))?;
+        pos = end - input[unit_end..].trim_start().len();
+    }
+    Ok(sign * nanos)
+}
+
+/// Convert a number of units to (rounded) nanoseconds, exactly for whole numbers.
+fn unit_nanos(num: f64, mult: i128) -> Option<i128> {
+    let whole = num.trunc();
+    // past this, the cast to i128 saturates
+    if whole >= 2f64.powi(127) {
+        return None;
+    }
+    let frac = ((num - whole) * mult as f64).round() as i128;
+    (whole as i128).checked_mul(mult)?.checked_add(frac)
+}
+
+impl FromStr for HumanDuration {
+    type Err = ParseError;
+
+    /// Parse a duration (see [parse_duration]).  Negative durations are accepted.
+    fn from_str(s: &str) -> Result<HumanDuration, ParseError> {
+        parse_nanos(s).map(HumanDuration::new_from_nanos)
+    }
+}
+
+#[cfg(test)]
+fn kind<T>(res: Result<T, ParseError>) -> ParseErrorKind {
+    res.err().expect("parse succeeded").kind()
+}
+
+#[test]
+fn test_plain_bytes() {
+    assert_eq!(parse_bytes("532"), Ok(532));
+    assert_eq!(parse_bytes("532 B"), Ok(532));
+    assert_eq!(parse_bytes("  532B  "), Ok(532));
+}
+
+#[test]
+fn test_exact_bytes() {
+    assert_eq!(parse_bytes("13,200 bytes"), Ok(13_200));
+    assert_eq!(parse_bytes("1 byte"), Ok(1));
+}
+
+#[test]
+fn test_binary() {
+    assert_eq!(parse_bytes("1 KiB"), Ok(1024));
+    assert_eq!(parse_bytes("2GiB"), Ok(2 << 30));
+}
+
+#[test]
+fn test_decimal() {
+    assert_eq!(parse_bytes("13.20 kB"), Ok(13_200));
+    assert_eq!(parse_bytes("3 TB"), Ok(3_000_000_000_000));
+}
+
+#[test]
+fn test_round_trip() {
+    let s = crate::bytes(182_421).to_string();
+    let parsed = parse_bytes(&s).unwrap();
+    assert!((parsed as i64 - 182_421).abs() < 100);
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(kind(parse_bytes("")), ParseErrorKind::Empty);
+    assert_eq!(kind(parse_bytes("KiB")), ParseErrorKind::InvalidNumber);
+    assert_eq!(
+        kind(parse_bytes("1.2.3 KiB")),
+        ParseErrorKind::InvalidNumber
+    );
+    let err = parse_bytes("12 parsecs").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::UnknownUnit);
+    assert_eq!(err.found(), "parsecs");
+    let err = parse_bytes("5 mB").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::UnknownUnit);
+    assert_eq!(err.found(), "mB");
+    assert_eq!(kind(parse_bytes("-5 B")), ParseErrorKind::OutOfRange);
+    assert_eq!(kind(parse_bytes("100 YiB")), ParseErrorKind::OutOfRange);
+}
+
+#[test]
+fn test_subsecond_durations() {
+    assert_eq!(parse_duration("324.0ms"), Ok(Duration::from_millis(324)));
+    assert_eq!(parse_duration("5.293 s"), Ok(Duration::from_millis(5293)));
+    assert_eq!(parse_duration("250 μs"), Ok(Duration::from_micros(250)));
+}
+
+#[test]
+fn test_bare_seconds() {
+    assert_eq!(parse_duration("90"), Ok(Duration::from_secs(90)));
+}
+
+#[test]
+fn test_long_durations() {
+    let d = parse_duration("1 days 2 hours 3 minutes 4.50 seconds");
+    assert_eq!(d, Ok(Duration::from_millis(93_784_500)));
+}
+
+#[test]
+fn test_duration_round_trip() {
+    let d = Duration::from_secs(5 * 3600 + 32 * 60 + 10);
+    let s = crate::duration(d).to_string();
+    assert_eq!(parse_duration(&s), Ok(d));
+    let s = crate::duration(d).compact(false).to_string();
+    assert_eq!(parse_duration(&s), Ok(d));
+}
+
+#[test]
+fn test_long_duration_round_trip() {
+    let d = Duration::from_secs(100_000 * 604_800 + 2 * 86_400 + 3 * 3600);
+    assert_eq!(parse_duration("100000w2d3h"), Ok(d));
+    let s = crate::duration(d).to_string();
+    assert_eq!(parse_duration(&s), Ok(d));
+    // summed exactly, not as floating-point nanoseconds
+    let d = Duration::new(100_000 * 604_800, 1);
+    assert_eq!(parse_duration("100000w 1ns"), Ok(d));
+    let huge = format!("{}w", "9".repeat(40));
+    assert_eq!(kind(parse_duration(&huge)), ParseErrorKind::OutOfRange);
+    assert_eq!(
+        kind(parse_duration("40000000000000w")),
+        ParseErrorKind::OutOfRange
+    );
+}
+
+#[test]
+fn test_months_round_trip() {
+    let d = Duration::from_secs(400 * 86_400 + 3600);
+    let s = crate::duration(d).years_months(true).parts(5).to_string();
+    assert_eq!(s.as_str(), "1y1mo0w4d8h");
+    let back = parse_duration(&s).unwrap();
+    let err = back.as_secs_f64() - d.as_secs_f64();
+    // the display rounds to whole hours
+    assert!(err.abs() < 3600.0, "{} ≠ {:?}", s, d);
+    let s = crate::duration(d)
+        .years_months(true)
+        .compact(false)
+        .to_string();
+    assert!(parse_duration(&s).is_ok(), "{}", s);
+}
+
+#[test]
+fn test_duration_errors() {
+    assert_eq!(kind(parse_duration(" ")), ParseErrorKind::Empty);
+    let err = parse_duration("5h and").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::InvalidNumber);
+    assert_eq!(err.span(), 3..6);
+    let err = parse_duration("3 fortnights").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::UnknownUnit);
+    assert_eq!(err.found(), "fortnights");
+    assert_eq!(err.span(), 2..12);
+    assert_eq!(parse_duration("5"), Ok(Duration::from_secs(5)));
+    assert_eq!(kind(parse_duration("-5s")), ParseErrorKind::OutOfRange);
+}
+
+#[test]
+fn test_duration_from_str() {
+    let d: HumanDuration = "-1m30s".parse().unwrap();
+    assert_eq!(d.as_nanos(), -90_000_000_000);
+    let d: HumanDuration = " -2.5".parse().unwrap();
+    assert_eq!(d.as_nanos(), -2_500_000_000);
+    assert_eq!(
+        kind("1m-30s".parse::<HumanDuration>()),
+        ParseErrorKind::InvalidNumber
+    );
+    let d: HumanDuration = "5h32m10.5s".parse().unwrap();
+    assert_eq!(d.to_string().as_str(), "5h32m10.50s");
+}
+
+#[test]
+fn test_scalar_prefixes() {
+    assert_eq!(parse_scalar("42"), Ok(42.0));
+    assert_eq!(parse_scalar("15.25 G"), Ok(15.25e9));
+    assert_eq!(parse_scalar("3 n"), Ok(3e-9));
+    assert_eq!(parse_scalar("2 Q"), Ok(2e30));
+    assert_eq!(parse_scalar("-7.5k"), Ok(-7500.0));
+}
+
+#[test]
+fn test_scalar_micro() {
+    assert_eq!(parse_scalar("250μ"), Ok(250e-6));
+    assert_eq!(parse_scalar("250µ"), Ok(250e-6));
+    assert_eq!(parse_scalar("250u"), Ok(250e-6));
+}
+
+#[test]
+fn test_scalar_round_trip() {
+    let s = crate::scalar(0.023477).to_string();
+    assert_eq!(parse_scalar(&s), Ok(0.02348));
+}
+
+#[test]
+fn test_integer() {
+    assert_eq!(parse_integer("12k"), Ok(12_000));
+    assert_eq!(parse_integer("1.2345k"), Ok(1235));
+    assert_eq!(parse_integer("-3M"), Ok(-3_000_000));
+    assert_eq!(kind(parse_integer("100 E")), ParseErrorKind::OutOfRange);
+}
+
+#[test]
+fn test_scalar_errors() {
+    let err = parse_scalar("3 x").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::UnknownUnit);
+    assert_eq!(err.found(), "x");
+    assert_eq!(kind(parse_scalar("M")), ParseErrorKind::InvalidNumber);
+}
@@ -0,0 +1,513 @@
+//! Configurable parser for prefixed quantities.
+use super::{ParseError, ParseErrorKind};
+use crate::grouping::Grouping;
+use crate::scale::{Prefix, PrefixFamily};
+
+/// How strictly to interpret input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strictness {
+    /// Only accept exactly the formats this crate displays: no digit grouping, exact
+    /// prefix labels, at most one space, and the unit suffix is required.
+    Strict,
+    /// Accept common variations: digit grouping (in groups of 3, with the
+    /// [grouping](Parser::grouping) separator), case-insensitive prefixes (when
+    /// unambiguous), “µ” or “u” for micro, extra whitespace, and a bare prefix without
+    /// the unit suffix.
+    Lenient,
+}
+
+/// A known prefix.
+#[derive(Debug, Clone, Copy)]
+struct KnownPrefix {
+    label: &'static str,
+    base: i32,
+    exp: i32,
+}
+
+impl KnownPrefix {
+    /// Apply this prefix to a number.
+    fn apply(&self, num: f64) -> f64 {
+        let base = self.base as f64;
+        if self.exp < 0 {
+            // divide by the exact positive power to avoid rounding error
+            num / base.powi(-self.exp)
+        } else {
+            num * base.powi(self.exp)
+        }
+    }
+}
+
+/// A configurable parser for numbers with prefixes and units.
+///
+/// Parsers are configured with one or more [PrefixFamily]s, a unit suffix, and a
+/// [Strictness], and parse values into the unprefixed unit:
+///
+/// ```
+/// # use friendly::parse::*;
+/// # use friendly::scale::*;
+/// let parser = Parser::new().family::<Binary>().suffix("B");
+/// assert_eq!(parser.parse("1.5 KiB"), Ok(1536.0));
+/// let err = parser.strictness(Strictness::Strict).parse("1.5 Ki").unwrap_err();
+/// assert_eq!(err.kind(), ParseErrorKind::UnknownUnit);
+/// ```
+///
+/// Parsers are useful for implementing [std::str::FromStr] for your own types:
+///
+/// ```
+/// # use std::str::FromStr;
+/// # use friendly::parse::*;
+/// # use friendly::scale::*;
+/// struct Power(f64);
+///
+/// impl FromStr for Power {
+///     type Err = ParseError;
+///
+///     fn from_str(s: &str) -> Result<Power, ParseError> {
+///         Parser::new().family::<Decimal>().suffix("W").parse(s).map(Power)
+///     }
+/// }
+///
+/// let p: Power = "15.25 MW".parse().unwrap();
+/// assert_eq!(p.0, 15_250_000.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Parser {
+    prefixes: Vec<KnownPrefix>,
+    suffix: &'static str,
+    aliases: Vec<&'static str>,
+    fractional: bool,
+    strictness: Strictness,
+    point: char,
+    group: Grouping,
+}
+
+impl Default for Parser {
+    fn default() -> Parser {
+        Parser::new()
+    }
+}
+
+impl Parser {
+    /// Create a new lenient parser with no prefixes or units.
+    pub fn new() -> Parser {
+        Parser {
+            prefixes: Vec::new(),
+            suffix: "",
+            aliases: Vec::new(),
+            fractional: true,
+            strictness: Strictness::Lenient,
+            point: '.',
+            group: Grouping::Comma,
+        }
+    }
+
+    /// Accept the prefixes of a prefix family.
+    pub fn family<F: PrefixFamily>(mut self) -> Parser {
+        for p in F::all_prefixes() {
+            if !self.prefixes.iter().any(|k| k.label == p.label()) {
+                self.prefixes.push(KnownPrefix {
+                    label: p.label(),
+                    base: p.base(),
+                    exp: p.exponent(),
+                });
+            }
+        }
+        self
+    }
+
+    /// Set the unit suffix.
+    pub fn suffix(self, suffix: &'static str) -> Parser {
+        Parser { suffix, ..self }
+    }
+
+    /// Accept an alternate spelling of the unit, which cannot take a prefix
+    /// (e.g. “bytes” for “B”).
+    pub fn alias(mut self, alias: &'static str) -> Parser {
+        self.aliases.push(alias);
+        self
+    }
+
+    /// Set whether to accept prefixes that make values smaller (e.g. milli).
+    pub fn fractional_prefixes(self, fractional: bool) -> Parser {
+        Parser { fractional, ..self }
+    }
+
+    /// Set the strictness of the parser.
+    pub fn strictness(self, strictness: Strictness) -> Parser {
+        Parser { strictness, ..self }
+    }
+
+    /// Set the decimal separator, to read values written with
+    /// [Quantity::decimal_separator](crate::quantity::Quantity::decimal_separator).
+    ///
+    /// If the digit grouping uses the same character, it is switched to periods.
+    ///
+    /// ```
+    /// # use friendly::parse::*;
+    /// # use friendly::scale::*;
+    /// let p = Parser::new().family::<Binary>().suffix("B");
+    /// assert_eq!(p.clone().decimal_separator(',').parse("12,5 KiB"), Ok(12800.0));
+    /// // “12,5” is not a valid group of thousands
+    /// assert!(p.parse("12,5 KiB").is_err());
+    /// ```
+    pub fn decimal_separator(self, point: char) -> Parser {
+        let group = if self.group.separator() == Some(point) {
+            Grouping::Period
+        } else {
+            self.group
+        };
+        Parser {
+            point,
+            group,
+            ..self
+        }
+    }
+
+    /// Set the digit grouping accepted by lenient parsers (commas by default).
+    pub fn grouping(self, group: Grouping) -> Parser {
+        Parser { group, ..self }
+    }
+
+    /// Get the group separator to accept, if any.
+    fn group_separator(&self) -> Option<char> {
+        self.group.separator().filter(|_| self.lenient())
+    }
+
+    fn lenient(&self) -> bool {
+        self.strictness == Strictness::Lenient
+    }
+
+    /// Iterate over the prefixes this parser accepts.
+    fn accepted(&self) -> impl Iterator<Item = KnownPrefix> + '_ {
+        // always accept unprefixed values
+        let unit = if self.prefixes.iter().any(|p| p.exp == 0) {
+            None
+        } else {
+            Some(UNIT_PREFIX)
+        };
+        self.prefixes
+            .iter()
+            .copied()
+            .filter(move |p| self.fractional || p.exp >= 0)
+            .chain(unit)
+    }
+
+    /// Get the list of units this parser accepts, for error messages.
+    fn expected_units(&self) -> Vec<String> {
+        let mut units: Vec<String> = self
+            .accepted()
+            .map(|p| format!("{}{}", p.label, self.suffix))
+            .filter(|u| !u.is_empty())
+            .collect();
+        units.extend(self.aliases.iter().map(|a| a.to_string()));
+        units
+    }
+
+    /// Look up a prefix label.
+    ///
+    /// Prefixes excluded by [Parser::fractional_prefixes] still count for exact and
+    /// ambiguous matches, so e.g. “mB” is not taken to mean “MB”.
+    fn find_prefix(&self, label: &str) -> Option<KnownPrefix> {
+        let all = || self.prefixes.iter().copied().chain(Some(UNIT_PREFIX));
+        let acceptable = |p: KnownPrefix| Some(p).filter(|p| self.fractional || p.exp >= 0);
+        if let Some(p) = all().find(|p| p.label == label) {
+            return acceptable(p);
+        }
+        if !self.lenient() {
+            return None;
+        }
+        if matches!(label, "µ" | "u") {
+            return self.find_prefix("μ");
+        }
+        let mut matches = all().filter(|p| p.label.eq_ignore_ascii_case(label));
+        match (matches.next(), matches.next()) {
+            (Some(p), None) => acceptable(p),
+            _ => None,
+        }
+    }
+
+    /// Look up the prefix for a unit string.
+    fn find_unit(&self, unit: &str) -> Option<KnownPrefix> {
+        if self.aliases.contains(&unit) {
+            return Some(UNIT_PREFIX);
+        }
+        if unit.is_empty() && !self.suffix.is_empty() && !self.lenient() {
+            return None;
+        }
+        match unit.strip_suffix(self.suffix) {
+            Some(pfx) => self.find_prefix(pfx),
+            None if self.lenient() => self.find_prefix(unit),
+            None => None,
+        }
+    }
+
+    /// Read a number starting at `start`, returning it and the offset after it.
+    ///
+    /// The number must be followed by something other than numeric punctuation.
+    pub(crate) fn read_number(
+        &self,
+        input: &str,
+        start: usize,
+    ) -> Result<(f64, usize), ParseError> {
+        let rest = &input[start..];
+        let group = self.group.separator().filter(|c| !c.is_whitespace());
+        let len = rest
+            .find(|c: char| {
+                !(c.is_ascii_digit()
+                    || matches!(c, '.' | ',' | '-' | '+')
+                    || c == self.point
+                    || Some(c) == group)
+            })
+            .unwrap_or(rest.len());
+        let end = start + len;
+        let text = &input[start..end];
+        let invalid = || ParseError::new(ParseErrorKind::InvalidNumber, input, start..end);
+        if !text.contains(|c: char| c.is_ascii_digit()) {
+            let word = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            let err = ParseError::new(ParseErrorKind::InvalidNumber, input, start..start + word);
+            return Err(err.expecting(["number"]));
+        }
+        let (num, num_end) =
+            scan_number(input, start, self.point, self.group_separator()).ok_or_else(invalid)?;
+        // a separator that is not part of the number (e.g. “1,2”) makes it invalid
+        let mut next = input[num_end..].chars();
+        match next.next() {
+            Some(c) if c.is_ascii_digit() || c == self.point || matches!(c, '-' | '+') => {
+                Err(invalid())
+            }
+            Some(c) if Some(c) == self.group.separator() || matches!(c, '.' | ',') => {
+                match next.next() {
+                    Some(d) if d.is_ascii_digit() => Err(invalid()),
+                    _ => Ok((num, num_end)),
+                }
+            }
+            _ => Ok((num, num_end)),
+        }
+    }
+
+    /// Parse a value, returning it in the unprefixed unit.
+    pub fn parse(&self, input: &str) -> Result<f64, ParseError> {
+        let input_end = input.trim_end().len();
+        let start = input_end - input[..input_end].trim_start().len();
+        if start == input_end {
+            return Err(ParseError::new(
+                ParseErrorKind::Empty,
+                input,
+                0..input.len(),
+            ));
+        }
+        let (num, num_end) = self.read_number(&input[..input_end], start)?;
+
+        let space = input[num_end..input_end].len() - input[num_end..input_end].trim_start().len();
+        let unit_start = if self.lenient() || space <= 1 {
+            num_end + space
+        } else {
+            num_end
+        };
+        let unit = &input[unit_start..input_end];
+        match self.find_unit(unit) {
+            Some(pfx) => Ok(pfx.apply(num)),
+            None => Err(
+                ParseError::new(ParseErrorKind::UnknownUnit, input, unit_start..input_end)
+                    .expecting(self.expected_units()),
+            ),
+        }
+    }
+
+    /// Parse a non-negative integer value, rounding to the nearest integer.
+    pub fn parse_u64(&self, input: &str) -> Result<u64, ParseError> {
+        let val = self.parse(input)?.round();
+        if val < 0.0 || val > u64::MAX as f64 {
+            let range = input.trim();
+            let start = input.len() - input.trim_start().len();
+            Err(ParseError::new(
+                ParseErrorKind::OutOfRange,
+                input,
+                start..start + range.len(),
+            ))
+        } else {
+            Ok(val as u64)
+        }
+    }
+
+    /// Parse an integer value, rounding to the nearest integer.
+    pub fn parse_i64(&self, input: &str) -> Result<i64, ParseError> {
+        let val = self.parse(input)?.round();
+        if val < i64::MIN as f64 || val > i64::MAX as f64 {
+            let range = input.trim();
+            let start = input.len() - input.trim_start().len();
+            Err(ParseError::new(
+                ParseErrorKind::OutOfRange,
+                input,
+                start..start + range.len(),
+            ))
+        } else {
+            Ok(val as i64)
+        }
+    }
+}
+
+/// The unit prefix, for parsers whose families do not include it.
+const UNIT_PREFIX: KnownPrefix = KnownPrefix {
+    label: "",
+    base: 10,
+    exp: 0,
+};
+
+#[cfg(test)]
+use crate::scale::{Binary, Decimal};
+
+/// Scan a number (with an optional sign, groups of 3 digits, and decimal part)
+/// starting at `pos`, returning it and the offset after it.
+pub(crate) fn scan_number(
+    s: &str,
+    pos: usize,
+    point: char,
+    group: Option<char>,
+) -> Option<(f64, usize)> {
+    let mut text = String::new();
+    let mut i = pos;
+    let rest = &s[pos..];
+    if rest.starts_with(['-', '+']) {
+        text.push_str(&rest[..1]);
+        i += 1;
+    }
+    let digits = |from: usize| {
+        s[from..].len()
+            - s[from..]
+                .trim_start_matches(|c: char| c.is_ascii_digit())
+                .len()
+    };
+    let n = digits(i);
+    if n == 0 {
+        return None;
+    }
+    text.push_str(&s[i..i + n]);
+    i += n;
+    while let Some(g) = group.filter(|g| s[i..].starts_with(*g)) {
+        // a group separator must be followed by exactly 3 digits
+        let after = i + g.len_utf8();
+        if digits(after) != 3 {
+            break;
+        }
+        text.push_str(&s[after..after + 3]);
+        i = after + 3;
+    }
+    if s[i..].starts_with(point) {
+        let after = i + point.len_utf8();
+        let n = digits(after);
+        if n > 0 {
+            text.push('.');
+            text.push_str(&s[after..after + n]);
+            i = after + n;
+        }
+    }
+    let num: f64 = text.parse().ok()?;
+    Some((num, i))
+}
+
+#[test]
+fn test_plain() {
+    let p = Parser::new();
+    assert_eq!(p.parse("42.5"), Ok(42.5));
+    assert_eq!(p.parse("1,234"), Ok(1234.0));
+}
+
+#[test]
+fn test_strict_grouping() {
+    let p = Parser::new().strictness(Strictness::Strict);
+    let err = p.parse("1,234").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::InvalidNumber);
+    assert_eq!(err.span(), 0..5);
+}
+
+#[test]
+fn test_strict_space() {
+    let p = Parser::new().family::<Decimal>().suffix("W");
+    assert_eq!(p.parse("15  kW"), Ok(15000.0));
+    let p = p.strictness(Strictness::Strict);
+    assert_eq!(p.parse("15 kW"), Ok(15000.0));
+    assert_eq!(p.parse("15kW"), Ok(15000.0));
+    let err = p.parse("15  kW").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::UnknownUnit);
+    assert_eq!(err.span(), 2..6);
+}
+
+#[test]
+fn test_case_insensitive() {
+    let p = Parser::new().family::<Decimal>().suffix("B");
+    assert_eq!(p.parse("3 KB"), Ok(3000.0));
+    // m and M are ambiguous
+    assert_eq!(p.parse("3 MB"), Ok(3_000_000.0));
+    assert_eq!(p.parse("3 mB"), Ok(0.003));
+    let strict = p.strictness(Strictness::Strict);
+    assert_eq!(
+        strict.parse("3 KB").unwrap_err().kind(),
+        ParseErrorKind::UnknownUnit
+    );
+}
+
+#[test]
+fn test_bare_prefix() {
+    let p = Parser::new().family::<Binary>().suffix("B");
+    assert_eq!(p.parse("2 Gi"), Ok(2.0 * (1u64 << 30) as f64));
+    assert_eq!(p.parse("512"), Ok(512.0));
+    let strict = p.strictness(Strictness::Strict);
+    assert!(strict.parse("512").is_err());
+}
+
+#[test]
+fn test_multiple_families() {
+    let p = Parser::new().family::<Binary>().family::<Decimal>();
+    assert_eq!(p.parse("1 Ki"), Ok(1024.0));
+    assert_eq!(p.parse("1 k"), Ok(1000.0));
+}
+
+#[test]
+fn test_fractional_prefixes() {
+    let p = Parser::new()
+        .family::<Decimal>()
+        .suffix("B")
+        .fractional_prefixes(false);
+    let err = p.parse("5 nB").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::UnknownUnit);
+    assert!(!err.expected().iter().any(|e| e == "nB"));
+}
+
+#[test]
+fn test_empty() {
+    let err = Parser::new().parse("   ").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::Empty);
+}
+
+#[test]
+fn test_invalid_span() {
+    let err = Parser::new().parse("  abc def").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::InvalidNumber);
+    assert_eq!(err.span(), 2..5);
+    assert_eq!(err.expected(), &["number".to_string()]);
+}
+
+#[test]
+fn test_decimal_comma() {
+    let p = Parser::new().family::<Binary>().suffix("B");
+    // the crate's own output with a decimal comma does not read as 1289 KiB
+    let out = crate::bytes(13200).decimal_separator(',').to_string();
+    assert_eq!(super::kind(p.parse(&out)), ParseErrorKind::InvalidNumber);
+    let p = p.decimal_separator(',');
+    assert_eq!(p.parse(&out), Ok(12.89 * 1024.0));
+    assert_eq!(p.parse("1.024,5 B"), Ok(1024.5));
+}
+
+#[test]
+fn test_grouping() {
+    let p = Parser::new();
+    assert_eq!(p.parse("1,234,567.5"), Ok(1_234_567.5));
+    assert_eq!(super::kind(p.parse("1,23")), ParseErrorKind::InvalidNumber);
+    assert_eq!(
+        super::kind(p.parse("1,2345")),
+        ParseErrorKind::InvalidNumber
+    );
+    let p = p.grouping(Grouping::Space);
+    assert_eq!(p.parse("12 345"), Ok(12_345.0));
+}
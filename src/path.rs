@@ -0,0 +1,151 @@
+//! Display of shortened file paths.
+use std::borrow::Cow;
+use std::env;
+use std::fmt;
+use std::path::{Component, Path, PathBuf, MAIN_SEPARATOR};
+
+use unicode_width::UnicodeWidthStr;
+
+/// A file path to display, with the home directory abbreviated to “~”.
+///
+/// Long paths can also be shortened by eliding components from the middle:
+///
+/// ```
+/// # use friendly::path;
+/// let p = path("/home/user/projects/app/crates/core/target/release").home("/home/user");
+/// assert_eq!(p.to_string().as_str(), "~/projects/app/crates/core/target/release");
+/// assert_eq!(p.clone().max_width(28).to_string().as_str(), "~/projects/…/target/release");
+/// ```
+///
+/// The home directory is taken from the `HOME` (or `USERPROFILE`) environment
+/// variable unless it is set with [HumanPath::home].
+#[derive(Debug, Clone)]
+pub struct HumanPath<'a> {
+    path: &'a Path,
+    home: Option<PathBuf>,
+    max_width: Option<usize>,
+}
+
+/// Display a file path.
+pub fn path<P: AsRef<Path> + ?Sized>(path: &P) -> HumanPath<'_> {
+    HumanPath::new(path.as_ref())
+}
+
+impl<'a> HumanPath<'a> {
+    /// Create a new displayable path.
+    pub fn new(path: &'a Path) -> Self {
+        let home = env::var_os("HOME")
+            .or_else(|| env::var_os("USERPROFILE"))
+            .filter(|h| !h.is_empty())
+            .map(PathBuf::from);
+        HumanPath {
+            path,
+            home,
+            max_width: None,
+        }
+    }
+
+    /// Set the home directory to abbreviate.
+    pub fn home<H: Into<PathBuf>>(self, home: H) -> Self {
+        HumanPath {
+            home: Some(home.into()),
+            ..self
+        }
+    }
+
+    /// Display the path without abbreviating the home directory.
+    pub fn without_home(self) -> Self {
+        HumanPath { home: None, ..self }
+    }
+
+    /// Set a maximum display width, eliding components from the middle of longer
+    /// paths.  The first and last components are always kept.
+    pub fn max_width(self, width: usize) -> Self {
+        HumanPath {
+            max_width: Some(width),
+            ..self
+        }
+    }
+
+    /// Get the components to display.
+    fn parts(&self) -> Vec<Cow<'a, str>> {
+        let (head, rest) = match self.home.as_deref().map(|h| self.path.strip_prefix(h)) {
+            Some(Ok(rest)) => (Some(Cow::Borrowed("~")), rest),
+            _ => (None, self.path),
+        };
+        let has_prefix = matches!(rest.components().next(), Some(Component::Prefix(_)));
+        let comps = rest.components().filter_map(move |c| match c {
+            // a drive prefix (“C:”) already joins to the next component with a separator
+            Component::RootDir if has_prefix => None,
+            // otherwise, the root joins to the next component as a leading separator
+            Component::RootDir => Some(Cow::Borrowed("")),
+            c => Some(c.as_os_str().to_string_lossy()),
+        });
+        head.into_iter().chain(comps).collect()
+    }
+}
+
+/// Get the display width of path components joined by separators.
+fn joined_width<S: AsRef<str>>(parts: &[S]) -> usize {
+    let seps = parts.len().saturating_sub(1);
+    parts.iter().map(|p| p.as_ref().width()).sum::<usize>() + seps
+}
+
+impl fmt::Display for HumanPath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts = self.parts();
+        let sep = MAIN_SEPARATOR.to_string();
+        let mut out = parts.join(&sep);
+        if parts.len() == 1 && parts[0].is_empty() {
+            // the root directory alone
+            out = sep.clone();
+        }
+
+        if let Some(max) = self.max_width.filter(|m| *m < joined_width(&parts)) {
+            let n = parts.len();
+            let elided = |front: usize, back: usize| {
+                let mut kept: Vec<&str> = parts[..front].iter().map(|p| p.as_ref()).collect();
+                kept.push("…");
+                kept.extend(parts[n - back..].iter().map(|p| p.as_ref()));
+                kept
+            };
+            // keep components alternately from the end and the start while they fit
+            let (mut front, mut back) = (1, 1);
+            loop {
+                let (f2, b2) = if back <= front {
+                    (front, back + 1)
+                } else {
+                    (front + 1, back)
+                };
+                if f2 + b2 >= n || joined_width(&elided(f2, b2)) > max {
+                    break;
+                }
+                (front, back) = (f2, b2);
+            }
+            if front + back < n {
+                out = elided(front, back).join(&sep);
+            }
+        }
+        f.pad(&out)
+    }
+}
+
+#[test]
+fn test_outside_home() {
+    let p = path("/usr/local/bin").home("/home/user");
+    assert_eq!(p.to_string().as_str(), "/usr/local/bin");
+    let p = path("/home/username").home("/home/user");
+    assert_eq!(p.to_string().as_str(), "/home/username");
+    let p = path("/").without_home();
+    assert_eq!(p.to_string().as_str(), "/");
+}
+
+#[test]
+fn test_elide_absolute() {
+    let p = path("/var/lib/service/data/cache/objects/pack").without_home();
+    assert_eq!(
+        p.clone().max_width(20).to_string().as_str(),
+        "/var/…/objects/pack"
+    );
+    assert_eq!(p.max_width(5).to_string().as_str(), "/…/pack");
+}
@@ -0,0 +1,290 @@
+//! Display of percentages, per-mille values, and basis points.
+use std::fmt;
+
+use crate::buffer::StackBuf;
+use crate::numfmt::trimmed_precision;
+use crate::sigfig::sigscale;
+
+/// The unit in which to display a [Percent].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PerUnit {
+    /// Parts per hundred (“%”).
+    #[default]
+    Percent,
+    /// Parts per thousand (“‰”).
+    Permille,
+    /// Parts per ten thousand (“bp”).
+    BasisPoint,
+}
+
+impl PerUnit {
+    /// Get the number of units in a whole.
+    pub fn whole(&self) -> f64 {
+        match self {
+            PerUnit::Percent => 100.0,
+            PerUnit::Permille => 1000.0,
+            PerUnit::BasisPoint => 10000.0,
+        }
+    }
+
+    /// Get the symbol (with any leading space) for this unit.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            PerUnit::Percent => "%",
+            PerUnit::Permille => "‰",
+            PerUnit::BasisPoint => " bp",
+        }
+    }
+}
+
+/// A value to display as a percentage (from [percent](crate::percent())).
+///
+/// The value is a fraction by default (0.4237 is 42.37%), displayed with 4 significant
+/// figures:
+///
+/// ```
+/// # use friendly::percent;
+/// assert_eq!(percent(0.4237).to_string().as_str(), "42.37%");
+/// assert_eq!(percent(42.37).fraction(false).sig_figs(2).to_string().as_str(), "42%");
+/// assert_eq!(percent(1.2).clamp(true).to_string().as_str(), "100.0%");
+/// ```
+///
+/// It can also be displayed per-mille or in basis points, which [trim trailing
+/// zeros](Percent::trim_zeros) by default:
+///
+/// ```
+/// # use friendly::{permille, basis_points};
+/// assert_eq!(permille(0.0042).to_string().as_str(), "4.2‰");
+/// assert_eq!(basis_points(0.0037).to_string().as_str(), "37 bp");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Percent {
+    value: f64,
+    unit: PerUnit,
+    fraction: bool,
+    clamp: bool,
+    nsig: u32,
+    trim: Option<bool>,
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+impl Percent {
+    /// Create a new displayable percentage from a fraction.
+    pub fn new(value: f64) -> Percent {
+        Percent {
+            value,
+            unit: PerUnit::Percent,
+            fraction: true,
+            clamp: false,
+            nsig: 4,
+            trim: None,
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Set the unit to display.
+    pub fn unit(self, unit: PerUnit) -> Percent {
+        Percent { unit, ..self }
+    }
+
+    /// Display as per-mille (“‰”) instead of percent.
+    pub fn permille(self) -> Percent {
+        self.unit(PerUnit::Permille)
+    }
+
+    /// Display in basis points (“bp”) instead of percent.
+    pub fn basis_points(self) -> Percent {
+        self.unit(PerUnit::BasisPoint)
+    }
+
+    /// Set whether the value is a fraction (`true`, the default) or is already
+    /// expressed in the display unit.
+    pub fn fraction(self, fraction: bool) -> Percent {
+        Percent { fraction, ..self }
+    }
+
+    /// Set whether to clamp the value to the range 0–100%.
+    pub fn clamp(self, clamp: bool) -> Percent {
+        Percent { clamp, ..self }
+    }
+
+    /// Set the number of significant figures.
+    pub fn sig_figs(self, nsig: u32) -> Percent {
+        Percent { nsig, ..self }
+    }
+
+    /// Set whether to trim trailing zeros (“4.2‰” instead of “4.200‰”).
+    ///
+    /// Per-mille values and basis points are trimmed by default; percentages keep
+    /// their trailing zeros to show their precision.
+    ///
+    /// ```
+    /// # use friendly::{percent, permille};
+    /// assert_eq!(percent(0.05).trim_zeros(true).to_string().as_str(), "5%");
+    /// assert_eq!(permille(0.0042).trim_zeros(false).to_string().as_str(), "4.200‰");
+    /// ```
+    pub fn trim_zeros(self, trim: bool) -> Percent {
+        Percent {
+            trim: Some(trim),
+            ..self
+        }
+    }
+
+    /// Display non-zero values smaller in magnitude than a floor (in the same terms
+    /// as the value) as the floor with a “<” sign.
+    ///
+    /// ```
+    /// # use friendly::percent;
+    /// assert_eq!(percent(0.00042).min_display(0.001).to_string().as_str(), "<0.1%");
+    /// ```
+    pub fn min_display(self, min: f64) -> Percent {
+        Percent {
+            min: Some(min),
+            ..self
+        }
+    }
+
+    /// Display values larger in magnitude than a ceiling (in the same terms as the
+    /// value) as the ceiling with a “>” sign.
+    ///
+    /// ```
+    /// # use friendly::percent;
+    /// assert_eq!(percent(12.5).max_display(9.99).to_string().as_str(), ">999%");
+    /// ```
+    pub fn max_display(self, max: f64) -> Percent {
+        Percent {
+            max: Some(max),
+            ..self
+        }
+    }
+
+    /// Convert a value to the display unit.
+    fn in_unit(&self, val: f64) -> f64 {
+        if self.fraction {
+            val * self.unit.whole()
+        } else {
+            val
+        }
+    }
+
+    /// Get the value in the display unit (after clamping).
+    pub fn scaled(&self) -> f64 {
+        let whole = self.unit.whole();
+        let val = self.in_unit(self.value);
+        if self.clamp {
+            val.clamp(0.0, whole)
+        } else {
+            val
+        }
+    }
+
+    /// Get the value as a percentage (after clamping).
+    pub fn percentage(&self) -> f64 {
+        self.scaled() * 100.0 / self.unit.whole()
+    }
+}
+
+impl Percent {
+    /// Write a display threshold (in the display unit) with a comparison sign.
+    fn write_threshold(&self, f: &mut dyn fmt::Write, sign: &str, val: f64) -> fmt::Result {
+        let (val, prec) = sigscale(val, self.nsig as usize);
+        let prec = trimmed_precision(val, prec);
+        write!(f, "{}{:.*}{}", sign, prec, val, self.unit.symbol())
+    }
+
+    /// Write the value without padding.
+    fn write_value(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        let val = self.scaled();
+        let sym = self.unit.symbol();
+        if val == 0.0 {
+            return write!(f, "0{}", sym);
+        }
+        if let Some(min) = self.min.map(|m| self.in_unit(m)).filter(|m| val.abs() < *m) {
+            let sign = if val < 0.0 { ">-" } else { "<" };
+            return self.write_threshold(f, sign, min);
+        }
+        if let Some(max) = self.max.map(|m| self.in_unit(m)).filter(|m| val.abs() > *m) {
+            let sign = if val < 0.0 { "<-" } else { ">" };
+            return self.write_threshold(f, sign, max);
+        }
+        let (val, prec) = sigscale(val, self.nsig as usize);
+        let trim = self.trim.unwrap_or(self.unit != PerUnit::Percent);
+        let prec = if trim {
+            trimmed_precision(val, prec)
+        } else {
+            prec
+        };
+        write!(f, "{:.*}{}", prec, val, sym)
+    }
+}
+
+impl fmt::Display for Percent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = StackBuf::new();
+        self.write_value(&mut buf)?;
+        buf.pad(f)
+    }
+}
+
+#[test]
+fn test_small() {
+    assert_eq!(Percent::new(0.000123).to_string().as_str(), "0.01230%");
+}
+
+#[test]
+fn test_clamp_negative() {
+    let p = Percent::new(-0.05);
+    assert_eq!(p.to_string().as_str(), "-5.000%");
+    assert_eq!(p.clamp(true).to_string().as_str(), "0%");
+}
+
+#[test]
+fn test_permille() {
+    let p = Percent::new(0.0042).permille();
+    assert_eq!(p.to_string().as_str(), "4.2‰");
+    let p = Percent::new(1.5).permille().clamp(true);
+    assert_eq!(p.to_string().as_str(), "1000‰");
+    assert_eq!(p.percentage(), 100.0);
+}
+
+#[test]
+fn test_basis_points() {
+    let p = Percent::new(0.0037).basis_points();
+    assert_eq!(p.to_string().as_str(), "37 bp");
+    let p = Percent::new(125.0).basis_points().fraction(false);
+    assert_eq!(p.to_string().as_str(), "125 bp");
+    let p = Percent::new(0.00125).basis_points();
+    assert_eq!(p.to_string().as_str(), "12.5 bp");
+    assert_eq!(
+        Percent::new(0.0).basis_points().to_string().as_str(),
+        "0 bp"
+    );
+}
+
+#[test]
+fn test_min_display() {
+    let p = Percent::new(0.00004).basis_points().min_display(0.0001);
+    assert_eq!(p.to_string().as_str(), "<1 bp");
+    let p = Percent::new(-0.02).min_display(0.05);
+    assert_eq!(p.to_string().as_str(), ">-5%");
+    assert_eq!(
+        Percent::new(0.0).min_display(0.05).to_string().as_str(),
+        "0%"
+    );
+}
+
+#[test]
+fn test_padding() {
+    let p = Percent::new(0.4237);
+    assert_eq!(format!("{:>8}", p).as_str(), "  42.37%");
+    assert_eq!(
+        format!("{:*<6}", Percent::new(0.0042).permille()).as_str(),
+        "4.2‰**"
+    );
+    assert_eq!(
+        format!("{:>6}", Percent::new(0.0).min_display(0.05)).as_str(),
+        "    0%"
+    );
+}
@@ -0,0 +1,108 @@
+//! Display of counts with agreeing nouns.
+use std::fmt;
+
+use crate::count::Count;
+use crate::quantity::QVal;
+
+/// A count displayed with a noun that agrees with it (“1 file”, “3 files”).
+///
+/// The count is displayed in compact form (see [Count]), so large counts are
+/// abbreviated:
+///
+/// ```
+/// # use friendly::{pluralize, pluralize_irregular};
+/// assert_eq!(pluralize(1, "file").to_string().as_str(), "1 file");
+/// assert_eq!(pluralize(3, "file").to_string().as_str(), "3 files");
+/// assert_eq!(pluralize(1234, "file").to_string().as_str(), "1.2K files");
+/// assert_eq!(pluralize(2, "match").to_string().as_str(), "2 matches");
+/// assert_eq!(pluralize_irregular(5, "person", "people").to_string().as_str(), "5 people");
+/// ```
+///
+/// Regular plurals follow the usual English spelling rules (“-s”, “-es”, and “-ies”).
+#[derive(Debug, Clone)]
+pub struct Plural<V: QVal> {
+    count: Count<V>,
+    one: bool,
+    singular: &'static str,
+    plural: Option<&'static str>,
+}
+
+/// Display a count with a noun, pluralized by the English spelling rules.
+pub fn pluralize<V: QVal>(value: V, singular: &'static str) -> Plural<V> {
+    Plural::new(value, singular, None)
+}
+
+/// Display a count with a noun that has an irregular plural.
+pub fn pluralize_irregular<V: QVal>(
+    value: V,
+    singular: &'static str,
+    plural: &'static str,
+) -> Plural<V> {
+    Plural::new(value, singular, Some(plural))
+}
+
+impl<V: QVal> Plural<V> {
+    /// Create a new count with a noun.  If no plural is given, it is formed by the
+    /// English spelling rules.
+    pub fn new(value: V, singular: &'static str, plural: Option<&'static str>) -> Self {
+        Plural {
+            one: value.as_float() == 1.0,
+            count: Count::new(value),
+            singular,
+            plural,
+        }
+    }
+
+    /// Set the maximum number of decimal places for abbreviated counts.
+    pub fn decimals(self, decimals: usize) -> Self {
+        Plural {
+            count: self.count.decimals(decimals),
+            ..self
+        }
+    }
+}
+
+/// Write the regular English plural of a noun.
+fn write_plural(f: &mut fmt::Formatter<'_>, word: &str) -> fmt::Result {
+    let bytes = word.as_bytes();
+    let ends_with = |e: &str| {
+        bytes.len() >= e.len() && bytes[bytes.len() - e.len()..].eq_ignore_ascii_case(e.as_bytes())
+    };
+    if ["s", "x", "z", "ch", "sh"].into_iter().any(ends_with) {
+        write!(f, "{}es", word)
+    } else if let Some(stem) = word.strip_suffix(['y', 'Y']) {
+        if stem.ends_with(['a', 'e', 'i', 'o', 'u', 'A', 'E', 'I', 'O', 'U']) {
+            write!(f, "{}s", word)
+        } else {
+            write!(f, "{}ies", stem)
+        }
+    } else {
+        write!(f, "{}s", word)
+    }
+}
+
+impl<V: QVal> fmt::Display for Plural<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ", self.count)?;
+        match (self.one, self.plural) {
+            (true, _) => f.write_str(self.singular),
+            (false, Some(p)) => f.write_str(p),
+            (false, None) => write_plural(f, self.singular),
+        }
+    }
+}
+
+#[test]
+fn test_spelling() {
+    assert_eq!(pluralize(2, "box").to_string().as_str(), "2 boxes");
+    assert_eq!(pluralize(2, "entry").to_string().as_str(), "2 entries");
+    assert_eq!(pluralize(2, "key").to_string().as_str(), "2 keys");
+    assert_eq!(pluralize(0, "dish").to_string().as_str(), "0 dishes");
+    assert_eq!(pluralize(3, "FAX").to_string().as_str(), "3 FAXes");
+}
+
+#[test]
+fn test_fractional() {
+    assert_eq!(pluralize(1.5, "byte").to_string().as_str(), "1.5 bytes");
+    assert_eq!(pluralize(1.0, "byte").to_string().as_str(), "1 byte");
+}
@@ -0,0 +1,8 @@
+//! Convenient imports for humanizing values.
+//!
+//! ```
+//! use friendly::prelude::*;
+//! assert_eq!(13200u64.human_bytes().to_string().as_str(), "12.89 KiB");
+//! ```
+pub use crate::ext::{Friendly, FriendlyDuration};
+pub use crate::{bytes, duration, scalar, Quantity};
@@ -0,0 +1,120 @@
+//! Display of probabilities and odds.
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::grouping::write_grouped;
+use crate::sigfig::sigscale;
+
+/// A probability to display as odds or a percentage.
+///
+/// Probabilities below a threshold (1% by default) are displayed as “1 in N” odds,
+/// with the denominator rounded to 2 significant figures; larger probabilities are
+/// displayed as percentages with 3 significant figures:
+///
+/// ```
+/// # use friendly::odds;
+/// assert_eq!(odds(0.000286).to_string().as_str(), "1 in 3,500");
+/// assert_eq!(odds(0.4237).to_string().as_str(), "42.4%");
+/// ```
+///
+/// Probabilities outside [0, 1] are clamped (so `odds(-0.5)` displays as “0.000%”),
+/// and NaN is displayed as “NaN” or its [placeholder](Odds::non_finite).
+#[derive(Debug, Clone)]
+pub struct Odds {
+    prob: f64,
+    threshold: f64,
+    nsig: u32,
+    non_finite: Option<Cow<'static, str>>,
+}
+
+/// Display a probability, choosing between odds and percentage by magnitude.
+pub fn odds(prob: f64) -> Odds {
+    Odds::new(prob)
+}
+
+impl Odds {
+    /// Create a new displayable probability.
+    pub fn new(prob: f64) -> Odds {
+        Odds {
+            prob,
+            threshold: 0.01,
+            nsig: 2,
+            non_finite: None,
+        }
+    }
+
+    /// Set the probability below which “1 in N” odds are displayed.
+    pub fn threshold(self, threshold: f64) -> Odds {
+        Odds { threshold, ..self }
+    }
+
+    /// Set the number of significant figures for the odds denominator.
+    pub fn sig_figs(self, nsig: u32) -> Odds {
+        Odds { nsig, ..self }
+    }
+
+    /// Display a placeholder in place of a NaN probability.
+    ///
+    /// ```
+    /// # use friendly::odds;
+    /// assert_eq!(odds(f64::NAN).non_finite("—").to_string().as_str(), "—");
+    /// ```
+    pub fn non_finite<S: Into<Cow<'static, str>>>(self, text: S) -> Odds {
+        Odds {
+            non_finite: Some(text.into()),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for Odds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.prob.is_nan() {
+            return f.write_str(self.non_finite.as_deref().unwrap_or("NaN"));
+        }
+        let prob = self.prob.clamp(0.0, 1.0);
+        if prob > 0.0 && prob < self.threshold {
+            let (denom, _prec) = sigscale(1.0 / prob, self.nsig as usize);
+            f.write_str("1 in ")?;
+            write_grouped(f, &format!("{:.0}", denom), ',')
+        } else {
+            let (pct, prec) = sigscale(prob * 100.0, 3);
+            write!(f, "{:.*}%", prec, pct)
+        }
+    }
+}
+
+#[test]
+fn test_one_in_million() {
+    assert_eq!(odds(1e-6).to_string().as_str(), "1 in 1,000,000");
+}
+
+#[test]
+fn test_rounded_denominator() {
+    assert_eq!(odds(0.003).to_string().as_str(), "1 in 330");
+    assert_eq!(odds(0.003).sig_figs(3).to_string().as_str(), "1 in 333");
+}
+
+#[test]
+fn test_threshold() {
+    assert_eq!(odds(0.02).to_string().as_str(), "2.00%");
+    assert_eq!(odds(0.02).threshold(0.05).to_string().as_str(), "1 in 50");
+}
+
+#[test]
+fn test_certain() {
+    assert_eq!(odds(1.0).to_string().as_str(), "100%");
+}
+
+#[test]
+fn test_zero() {
+    assert_eq!(odds(0.0).to_string().as_str(), "0.000%");
+}
+
+#[test]
+fn test_out_of_range() {
+    assert_eq!(odds(-0.5).to_string().as_str(), "0.000%");
+    assert_eq!(odds(1.5).to_string().as_str(), "100%");
+    assert_eq!(odds(f64::INFINITY).to_string().as_str(), "100%");
+    assert_eq!(odds(f64::NAN).to_string().as_str(), "NaN");
+}
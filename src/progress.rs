@@ -0,0 +1,170 @@
+//! Display of progress towards a total.
+use std::fmt;
+use std::time::Duration;
+
+use crate::buffer::StackBuf;
+use crate::quantity::{QVal, Quantity};
+use crate::scale::{Binary, PrefixFamily, Scale};
+use crate::temporal::HumanDuration;
+
+/// Progress towards a total, displayed with a shared prefix and a percentage.
+///
+/// ```
+/// # use friendly::progress;
+/// let p = progress(3_435_973_837u64, 8_589_934_592u64);
+/// assert_eq!(p.to_string().as_str(), "3.2 GiB / 8.0 GiB (40%)");
+/// assert_eq!(p.compact(true).to_string().as_str(), "3.2/8.0 GiB");
+/// ```
+///
+/// The percentage is rounded down, so it only shows 100% when the work is complete.
+#[derive(Debug, Clone)]
+pub struct Progress<Q: QVal, F: PrefixFamily> {
+    done: Quantity<Q, F>,
+    total: Quantity<Q, F>,
+    compact: bool,
+}
+
+/// Display progress through a number of bytes, with one decimal place.
+pub fn progress<V: QVal>(done: V, total: V) -> Progress<V, Binary> {
+    Progress::new(
+        crate::bytes(done).decimals(1),
+        crate::bytes(total).decimals(1),
+    )
+}
+
+impl<Q: QVal, F: PrefixFamily> Progress<Q, F> {
+    /// Create a new progress display.
+    pub fn new(done: Quantity<Q, F>, total: Quantity<Q, F>) -> Self {
+        Progress {
+            done,
+            total,
+            compact: false,
+        }
+    }
+
+    /// Set whether to use the compact form, which writes the unit once and omits
+    /// the percentage (“3.2/8.0 GiB”).
+    pub fn compact(self, compact: bool) -> Self {
+        Progress { compact, ..self }
+    }
+
+    /// Get the fraction of the total that is done, if the total is nonzero.
+    pub fn fraction(&self) -> Option<f64> {
+        let total = self.total.as_float();
+        if total != 0.0 && total.is_finite() {
+            Some(self.done.as_float() / total)
+        } else {
+            None
+        }
+    }
+
+    /// Get the scale shared by both values.
+    fn shared_scale(&self) -> Scale<F> {
+        let larger = if self.done.as_float().abs() > self.total.as_float().abs() {
+            &self.done
+        } else {
+            &self.total
+        };
+        match larger.resolve() {
+            Some((_v, _prec, pfx)) => Scale::Fixed(pfx),
+            None => Scale::Native,
+        }
+    }
+}
+
+impl<Q: QVal, F: PrefixFamily> fmt::Display for Progress<Q, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+        let scale = self.shared_scale();
+        let mut buf = StackBuf::new();
+        let form = self.done.write_value(&mut buf, &scale)?;
+        if self.compact {
+            buf.write_char('/')?;
+        } else {
+            self.done.write_unit(&mut buf, &form)?;
+            buf.write_str(" / ")?;
+        }
+        let form = self.total.write_value(&mut buf, &scale)?;
+        self.total.write_unit(&mut buf, &form)?;
+        if let (false, Some(frac)) = (self.compact, self.fraction()) {
+            write!(buf, " ({}%)", (frac * 100.0).floor())?;
+        }
+        buf.pad(f)
+    }
+}
+
+/// An estimate of the time remaining, assuming the rate so far continues.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use friendly::eta;
+/// let e = eta(250u64, 1000u64, Duration::from_secs(30));
+/// assert_eq!(e.to_string().as_str(), "1m30.00s");
+/// let e = eta(0u64, 1000u64, Duration::from_secs(30));
+/// assert_eq!(e.to_string().as_str(), "unknown");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Eta {
+    remaining: Option<Duration>,
+}
+
+/// Estimate the time remaining to finish a total, given the progress and time so far.
+pub fn eta<V: QVal>(done: V, total: V, elapsed: Duration) -> Eta {
+    let done = done.as_float();
+    let total = total.as_float();
+    let remaining = if done >= total {
+        Some(Duration::ZERO)
+    } else if done > 0.0 {
+        Duration::try_from_secs_f64(elapsed.as_secs_f64() * (total - done) / done).ok()
+    } else {
+        None
+    };
+    Eta { remaining }
+}
+
+impl Eta {
+    /// Get the estimated time remaining, if it can be estimated.
+    pub fn remaining(&self) -> Option<Duration> {
+        self.remaining
+    }
+}
+
+impl fmt::Display for Eta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.remaining {
+            Some(d) => HumanDuration::from(d).fmt(f),
+            None => f.pad("unknown"),
+        }
+    }
+}
+
+#[test]
+fn test_not_quite_done() {
+    let p = progress(8_589_934_591u64, 8_589_934_592u64);
+    assert_eq!(p.to_string().as_str(), "8.0 GiB / 8.0 GiB (99%)");
+}
+
+#[test]
+fn test_zero_total() {
+    let p = progress(0u64, 0u64);
+    assert_eq!(p.to_string().as_str(), "0 B / 0 B");
+}
+
+#[test]
+fn test_quantities() {
+    use crate::integer;
+    let p = Progress::new(integer(1250), integer(20_000));
+    assert_eq!(p.to_string().as_str(), "1.250 k / 20.00 k (6%)");
+}
+
+#[test]
+fn test_eta_done() {
+    let e = eta(1000u64, 1000u64, Duration::from_secs(30));
+    assert_eq!(e.to_string().as_str(), "0.0000s");
+}
+
+#[test]
+fn test_eta_no_time() {
+    let e = eta(10u64, 1000u64, Duration::ZERO);
+    assert_eq!(e.to_string().as_str(), "0.0000s");
+}
@@ -1,20 +1,189 @@
 //! General-purpose quantities with scales.
 use num_traits::ToPrimitive;
-use std::f64::NAN;
-use std::fmt;
+use std::borrow::Cow;
+use std::fmt::{self, Write};
+use std::ops::RangeInclusive;
 
+use crate::ascii::AsciiWriter;
+use crate::buffer::StackBuf;
+use crate::grouping::{write_number, Grouping};
+use crate::notation::{write_exponent, write_power, Notation};
+use crate::numfmt::{trimmed_precision, write_fixed, write_int_fixed};
+use crate::scale::prefix_range;
 use crate::scale::*;
 use crate::sigfig::*;
+use crate::spacing::SpaceStyle;
 
 /// Trait for values for a quantity.
+///
+/// The value's [fmt::Display] implementation is used when the quantity is displayed
+/// unscaled, so types that can display exact values (such as large integers) keep
+/// their exactness in that case.
+///
+/// This is implemented for the primitive numeric types and, with features, for some
+/// numeric types from other crates.  Before version 0.3 it was implemented for every
+/// [ToPrimitive] type; such types can still be displayed by wrapping them in
+/// [Primitive].
 pub trait QVal: fmt::Display {
     /// Convert to a floating-point value.
     fn as_float(&self) -> f64;
+
+    /// Query whether this value is of an integer type.
+    fn is_integer(&self) -> bool {
+        false
+    }
+
+    /// Get the sign (`true` if negative) and magnitude of an integer value.
+    ///
+    /// Integers too large to be exact as floats are scaled with this, so their
+    /// displayed digits stay exact.
+    fn as_exact_int(&self) -> Option<(bool, u128)> {
+        None
+    }
+
+    /// Get the sign, digits, and number of digits after the point of a value with an
+    /// exact decimal representation.
+    ///
+    /// Values with an exact decimal form are rounded for display in decimal
+    /// arithmetic, instead of through floats.  By default, this is the exact integer
+    /// (if any).
+    fn as_exact_decimal(&self) -> Option<(bool, u128, u32)> {
+        self.as_exact_int().map(|(neg, mag)| (neg, mag, 0))
+    }
+}
+
+macro_rules! primitive_qval {
+    ($int:literal; $($t:ty),*) => {
+        $(
+            impl QVal for $t {
+                fn as_float(&self) -> f64 {
+                    self.to_f64().unwrap_or(f64::NAN)
+                }
+
+                fn is_integer(&self) -> bool {
+                    $int
+                }
+
+                fn as_exact_int(&self) -> Option<(bool, u128)> {
+                    if !$int {
+                        None
+                    } else if let Some(u) = self.to_u128() {
+                        Some((false, u))
+                    } else {
+                        self.to_i128().map(|i| (true, i.unsigned_abs()))
+                    }
+                }
+            }
+        )*
+    };
 }
 
-impl<V: ToPrimitive + fmt::Display> QVal for V {
+primitive_qval!(true; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+primitive_qval!(false; f32, f64);
+
+/// A wrapper to use any [ToPrimitive] type as a quantity value.
+///
+/// ```
+/// # use friendly::quantity::{Primitive, Quantity};
+/// let w = std::num::Wrapping(13200u32);
+/// assert_eq!(Quantity::binary(Primitive(w)).to_string().as_str(), "12.89 Ki");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Primitive<V>(pub V);
+
+impl<V: fmt::Display> fmt::Display for Primitive<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<V: ToPrimitive + fmt::Display> QVal for Primitive<V> {
     fn as_float(&self) -> f64 {
-        self.to_f64().unwrap_or(NAN)
+        self.0.to_f64().unwrap_or(f64::NAN)
+    }
+}
+
+/// JSON numbers (with the `json` feature).
+///
+/// Integers stored as `u64` or `i64` display exactly when unscaled.
+#[cfg(feature = "json")]
+impl QVal for serde_json::Number {
+    fn as_float(&self) -> f64 {
+        if let Some(u) = self.as_u64() {
+            u as f64
+        } else if let Some(i) = self.as_i64() {
+            i as f64
+        } else {
+            self.as_f64().unwrap_or(f64::NAN)
+        }
+    }
+
+    fn is_integer(&self) -> bool {
+        self.is_u64() || self.is_i64()
+    }
+
+    fn as_exact_int(&self) -> Option<(bool, u128)> {
+        match (self.as_u64(), self.as_i64()) {
+            (Some(u), _) => Some((false, u as u128)),
+            (None, Some(i)) => Some((i < 0, i.unsigned_abs() as u128)),
+            _ => None,
+        }
+    }
+}
+
+/// Decimal numbers (with the `rust_decimal` feature).
+///
+/// These are rounded for display in decimal arithmetic, so they display with full
+/// precision.
+#[cfg(feature = "rust_decimal")]
+impl QVal for rust_decimal::Decimal {
+    fn as_float(&self) -> f64 {
+        self.to_f64().unwrap_or(f64::NAN)
+    }
+
+    fn as_exact_decimal(&self) -> Option<(bool, u128, u32)> {
+        let m = self.mantissa();
+        Some((m < 0, m.unsigned_abs(), self.scale()))
+    }
+}
+
+/// Arbitrary-precision integers (with the `num-bigint` feature).
+///
+/// Integers within the range of `u128` are scaled exactly; larger values are scaled
+/// with their correctly-rounded float value, which keeps the displayed significant
+/// figures accurate.  Unscaled values display all their digits.
+#[cfg(feature = "num-bigint")]
+impl QVal for num_bigint::BigUint {
+    fn as_float(&self) -> f64 {
+        self.to_f64().unwrap_or(f64::INFINITY)
+    }
+
+    fn is_integer(&self) -> bool {
+        true
+    }
+
+    fn as_exact_int(&self) -> Option<(bool, u128)> {
+        self.to_u128().map(|u| (false, u))
+    }
+}
+
+/// Arbitrary-precision integers (with the `num-bigint` feature).
+#[cfg(feature = "num-bigint")]
+impl QVal for num_bigint::BigInt {
+    fn as_float(&self) -> f64 {
+        self.to_f64().unwrap_or(match self.sign() {
+            num_bigint::Sign::Minus => f64::NEG_INFINITY,
+            _ => f64::INFINITY,
+        })
+    }
+
+    fn is_integer(&self) -> bool {
+        true
+    }
+
+    fn as_exact_int(&self) -> Option<(bool, u128)> {
+        let neg = self.sign() == num_bigint::Sign::Minus;
+        self.magnitude().to_u128().map(|u| (neg, u))
     }
 }
 
@@ -29,10 +198,29 @@ impl<V: ToPrimitive + fmt::Display> QVal for V {
 pub struct Quantity<Q: QVal, F: PrefixFamily> {
     value: Q,
     scale: Scale<F>,
-    sfx_str: &'static str,
+    sfx_str: Cow<'static, str>,
+    sfx_pow: i32,
+    sfx_words: Option<(Cow<'static, str>, Cow<'static, str>)>,
     nsig: u32,
-    spc: bool,
+    spc: SpaceStyle,
     integral: bool,
+    auto_int: bool,
+    minor: bool,
+    pfx_style: PrefixStyle,
+    group: Grouping,
+    point: char,
+    notation: Notation,
+    ascii: bool,
+    sign: bool,
+    decimals: Option<usize>,
+    trim: bool,
+    rounding: Rounding,
+    err: Option<f64>,
+    min_disp: Option<f64>,
+    max_disp: Option<f64>,
+    non_finite: Option<[Cow<'static, str>; 3]>,
+    zero: Option<Cow<'static, str>>,
+    template: Option<Cow<'static, str>>,
 }
 
 impl<Q: QVal> Quantity<Q, Decimal> {
@@ -47,18 +235,50 @@ impl<Q: QVal> Quantity<Q, Binary> {
     pub fn binary(value: Q) -> Self {
         Quantity::new(value)
     }
+
+    /// Use JEDEC-style labels (“KB”, “MB”) for this quantity's binary prefixes.
+    pub fn jedec(self) -> Quantity<Q, Jedec> {
+        let scale = match self.scale {
+            Scale::Auto => Scale::Auto,
+            Scale::AutoRange(lo, hi) => Scale::AutoRange(lo.into(), hi.into()),
+            Scale::AutoWithin(pfxs) => Scale::AutoWithin(Jedec::relabel(pfxs)),
+            Scale::Native => Scale::Native,
+            Scale::Fixed(p) => Scale::Fixed(p.into()),
+        };
+        self.scale(scale)
+    }
 }
 
 impl<Q: QVal, F: PrefixFamily> Quantity<Q, F> {
     /// Create a new auto-scaled quantity of arbitrary prefix type.
     pub fn new(value: Q) -> Self {
+        let cfg = crate::config::defaults();
         Quantity {
             value,
             scale: Scale::Auto,
-            sfx_str: "",
-            nsig: 4,
-            spc: true,
+            sfx_str: Cow::Borrowed(""),
+            sfx_pow: 1,
+            sfx_words: None,
+            nsig: cfg.nsig,
+            spc: cfg.spc,
             integral: false,
+            auto_int: false,
+            minor: false,
+            pfx_style: PrefixStyle::Strict,
+            group: Grouping::None,
+            point: cfg.point,
+            notation: Notation::Prefix,
+            ascii: cfg.ascii,
+            sign: false,
+            decimals: None,
+            trim: false,
+            rounding: Rounding::HalfUp,
+            err: None,
+            min_disp: None,
+            max_disp: None,
+            non_finite: None,
+            zero: None,
+            template: None,
         }
     }
 
@@ -86,29 +306,304 @@ impl<Q: QVal, F: PrefixFamily> Quantity<Q, F> {
         Quantity {
             value: self.value,
             sfx_str: self.sfx_str,
+            sfx_pow: self.sfx_pow,
+            sfx_words: self.sfx_words,
             nsig: self.nsig,
             scale: scale.into(),
             spc: self.spc,
             integral: self.integral,
+            auto_int: self.auto_int,
+            minor: self.minor,
+            pfx_style: self.pfx_style,
+            group: self.group,
+            point: self.point,
+            notation: self.notation,
+            ascii: self.ascii,
+            sign: self.sign,
+            decimals: self.decimals,
+            trim: self.trim,
+            rounding: self.rounding,
+            err: self.err,
+            min_disp: self.min_disp,
+            max_disp: self.max_disp,
+            non_finite: self.non_finite,
+            zero: self.zero,
+            template: self.template,
+        }
+    }
+
+    /// Auto-scale this quantity, limited to prefixes between two bounds (inclusive).
+    ///
+    /// Values outside the bounds are displayed with the nearest bound:
+    ///
+    /// ```
+    /// # use friendly::bytes;
+    /// # use friendly::scale::Binary;
+    /// let q = bytes(5u64 << 40).scale_range(Binary::UNIT..=Binary::GIBI);
+    /// assert_eq!(q.to_string().as_str(), "5120 GiB");
+    /// ```
+    pub fn scale_range(self, range: RangeInclusive<F::Prefix>) -> Self {
+        let (lo, hi) = range.into_inner();
+        Quantity {
+            scale: Scale::AutoRange(lo, hi),
+            ..self
         }
     }
 
     /// Change the unit suffix on this quantity.
-    pub fn suffix(self, suffix: &'static str) -> Self {
+    ///
+    /// The suffix can be a static string or one built at runtime:
+    ///
+    /// ```
+    /// # use friendly::scalar;
+    /// let unit = format!("{}/s", "req");
+    /// assert_eq!(scalar(12_500).suffix(unit).to_string().as_str(), "12.50 kreq/s");
+    /// ```
+    pub fn suffix<S: Into<Cow<'static, str>>>(self, suffix: S) -> Self {
+        Quantity {
+            sfx_str: suffix.into(),
+            sfx_pow: 1,
+            ..self
+        }
+    }
+
+    /// Set singular and plural unit words for unscaled values, so small counts agree
+    /// with their unit (“1 byte”, “2 bytes”).
+    ///
+    /// Values displayed with a prefix keep the [suffix](Quantity::suffix), if there is
+    /// one:
+    ///
+    /// ```
+    /// # use friendly::bytes;
+    /// assert_eq!(bytes(1).suffix_plural("byte", "bytes").to_string().as_str(), "1 byte");
+    /// assert_eq!(bytes(2).suffix_plural("byte", "bytes").to_string().as_str(), "2 bytes");
+    /// let q = bytes(13200).suffix_plural("byte", "bytes");
+    /// assert_eq!(q.to_string().as_str(), "12.89 KiB");
+    /// ```
+    ///
+    /// Only a value displayed as exactly “1” takes the singular.
+    pub fn suffix_plural<S, P>(self, singular: S, plural: P) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+        P: Into<Cow<'static, str>>,
+    {
+        Quantity {
+            sfx_words: Some((singular.into(), plural.into())),
+            ..self
+        }
+    }
+
+    /// Change the unit suffix to a unit raised to a power, written as a superscript
+    /// (or as “^2” in [ASCII](Quantity::ascii) mode).
+    ///
+    /// The power applies to the prefixed unit, so prefixes are scaled by it as well:
+    ///
+    /// ```
+    /// # use friendly::scalar;
+    /// let q = scalar(0.0012).suffix_pow("m", 2).allow_minor_prefixes(true);
+    /// assert_eq!(q.to_string().as_str(), "12.00 cm²");
+    /// let q = scalar(2.5e9).suffix_pow("m", 3);
+    /// assert_eq!(q.to_string().as_str(), "2.500 km³");
+    /// assert_eq!(q.ascii(true).to_string().as_str(), "2.500 km^3");
+    /// ```
+    ///
+    /// In a compound unit with a denominator, the power applies to the denominator
+    /// and prefixes scale as usual:
+    ///
+    /// ```
+    /// # use friendly::scalar;
+    /// let q = scalar(9810.0).suffix_pow("m/s", 2);
+    /// assert_eq!(q.to_string().as_str(), "9.810 km/s²");
+    /// ```
+    pub fn suffix_pow<S: Into<Cow<'static, str>>>(self, suffix: S, pow: i32) -> Self {
         Quantity {
-            sfx_str: suffix,
+            sfx_str: suffix.into(),
+            sfx_pow: pow,
             ..self
         }
     }
 
-    /// Change whether to include a space before units.
-    pub fn space(self, spc: bool) -> Self {
-        Quantity { spc, ..self }
+    /// Change the space before units.
+    ///
+    /// This accepts a [SpaceStyle], or `true` or `false` for an ordinary space or none:
+    ///
+    /// ```
+    /// # use friendly::{bytes, SpaceStyle};
+    /// assert_eq!(bytes(13200).space(false).to_string().as_str(), "12.89KiB");
+    /// let q = bytes(13200).space(SpaceStyle::Nbsp);
+    /// assert_eq!(q.to_string().as_str(), "12.89\u{a0}KiB");
+    /// ```
+    pub fn space<S: Into<SpaceStyle>>(self, space: S) -> Self {
+        Quantity {
+            spc: space.into(),
+            ..self
+        }
     }
 
     /// Change the number of significant figures on this quantity.
+    ///
+    /// This replaces any fixed number of [decimals](Quantity::decimals).
     pub fn sig_figs(self, sf: u32) -> Self {
-        Quantity { nsig: sf, ..self }
+        Quantity {
+            nsig: sf,
+            decimals: None,
+            ..self
+        }
+    }
+
+    /// Display exactly this many digits after the decimal point, instead of a
+    /// number of significant figures.
+    ///
+    /// ```
+    /// # use friendly::bytes;
+    /// # use friendly::scale::Binary;
+    /// let q = bytes(512).scale(Binary::KIBI).decimals(2);
+    /// assert_eq!(q.to_string().as_str(), "0.50 KiB");
+    /// let q = bytes(123 << 20).decimals(2);
+    /// assert_eq!(q.to_string().as_str(), "123.00 MiB");
+    /// ```
+    pub fn decimals(self, decimals: usize) -> Self {
+        Quantity {
+            decimals: Some(decimals),
+            ..self
+        }
+    }
+
+    /// Change whether to drop trailing zeros after the decimal point.  The number is
+    /// still rounded to the significant figures (or decimals), so this never shows
+    /// more digits.
+    ///
+    /// ```
+    /// # use friendly::{bytes, scalar};
+    /// let q = bytes(1_572_864).trim_zeros(true);
+    /// assert_eq!(q.to_string().as_str(), "1.5 MiB");
+    /// let q = scalar(2.0).suffix("s").trim_zeros(true);
+    /// assert_eq!(q.to_string().as_str(), "2 s");
+    /// ```
+    ///
+    /// Values with an [uncertainty](Quantity::uncertainty) are not trimmed, since their
+    /// digits show the precision of the measurement.
+    pub fn trim_zeros(self, trim: bool) -> Self {
+        Quantity { trim, ..self }
+    }
+
+    /// Change how the displayed value is rounded.
+    ///
+    /// ```
+    /// # use friendly::bytes;
+    /// # use friendly::sigfig::Rounding;
+    /// let q = bytes(13200).rounding(Rounding::Floor);
+    /// assert_eq!(q.to_string().as_str(), "12.89 KiB");
+    /// let q = bytes(13200).rounding(Rounding::Ceil);
+    /// assert_eq!(q.to_string().as_str(), "12.90 KiB");
+    /// ```
+    pub fn rounding(self, rounding: Rounding) -> Self {
+        Quantity { rounding, ..self }
+    }
+
+    /// Display non-zero values smaller in magnitude than a floor as the floor with a
+    /// “<” sign, instead of a tiny value that is noise.
+    ///
+    /// ```
+    /// # use friendly::scalar;
+    /// let q = scalar(0.000_738_2).suffix("s").min_display(0.001);
+    /// assert_eq!(q.to_string().as_str(), "<1 ms");
+    /// let q = scalar(0.0042).suffix("s").min_display(0.001);
+    /// assert_eq!(q.to_string().as_str(), "4.200 ms");
+    /// ```
+    ///
+    /// The floor is displayed with this quantity's options, without trailing zeros.
+    pub fn min_display(self, min: f64) -> Self {
+        Quantity {
+            min_disp: Some(min),
+            ..self
+        }
+    }
+
+    /// Display values larger in magnitude than a ceiling as the ceiling with a “>”
+    /// sign, so the display has a bounded length.
+    ///
+    /// ```
+    /// # use friendly::bytes;
+    /// let cap = 999.0 * (1u64 << 30) as f64;
+    /// assert_eq!(bytes(5u64 << 40).max_display(cap).to_string().as_str(), ">999 GiB");
+    /// assert_eq!(bytes(3u64 << 30).max_display(cap).to_string().as_str(), "3.000 GiB");
+    /// ```
+    pub fn max_display(self, max: f64) -> Self {
+        Quantity {
+            max_disp: Some(max),
+            ..self
+        }
+    }
+
+    /// Display a placeholder in place of non-finite values (NaN and infinities),
+    /// instead of Rust's float text.
+    ///
+    /// ```
+    /// # use friendly::scalar;
+    /// let q = scalar(f64::NAN).suffix("B").non_finite("—");
+    /// assert_eq!(q.to_string().as_str(), "—");
+    /// assert_eq!(scalar(f64::NAN).suffix("B").to_string().as_str(), "NaN B");
+    /// ```
+    pub fn non_finite<S: Into<Cow<'static, str>>>(self, text: S) -> Self {
+        let text = text.into();
+        self.non_finite_each(text.clone(), text.clone(), text)
+    }
+
+    /// Display distinct placeholders for NaN, positive infinity, and negative infinity.
+    ///
+    /// ```
+    /// # use friendly::scalar;
+    /// let q = scalar(f64::NEG_INFINITY).non_finite_each("n/a", "∞", "-∞");
+    /// assert_eq!(q.to_string().as_str(), "-∞");
+    /// ```
+    pub fn non_finite_each<N, I, J>(self, nan: N, inf: I, neg_inf: J) -> Self
+    where
+        N: Into<Cow<'static, str>>,
+        I: Into<Cow<'static, str>>,
+        J: Into<Cow<'static, str>>,
+    {
+        Quantity {
+            non_finite: Some([nan.into(), inf.into(), neg_inf.into()]),
+            ..self
+        }
+    }
+
+    /// Display zero as fixed text, instead of with the usual precision (“0.0000 B”).
+    ///
+    /// ```
+    /// # use friendly::scalar;
+    /// let q = scalar(0.0).suffix("B").zero_display("0 B");
+    /// assert_eq!(q.to_string().as_str(), "0 B");
+    /// let q = scalar(0.0).suffix("B").zero_display("none");
+    /// assert_eq!(q.to_string().as_str(), "none");
+    /// ```
+    ///
+    /// To write zero as “0” with its unit without fixed text, use
+    /// [trim_zeros](Quantity::trim_zeros).
+    pub fn zero_display<S: Into<Cow<'static, str>>>(self, text: S) -> Self {
+        Quantity {
+            zero: Some(text.into()),
+            ..self
+        }
+    }
+
+    /// Display the quantity with an uncertainty (“12.3 ± 0.4 kB”).
+    ///
+    /// The uncertainty is rounded to 1 significant figure (2 if it begins with a 1),
+    /// and determines the rounding of the value instead of the significant figures.
+    /// It is only displayed in prefix [notation](Quantity::notation).
+    ///
+    /// ```
+    /// # use friendly::scalar;
+    /// let q = scalar(12_340).suffix("B").uncertainty(430.0);
+    /// assert_eq!(q.to_string().as_str(), "12.3 ± 0.4 kB");
+    /// ```
+    pub fn uncertainty(self, err: f64) -> Self {
+        Quantity {
+            err: Some(err),
+            ..self
+        }
     }
 
     /// Change whether the quantity is an integer.
@@ -118,40 +613,669 @@ impl<Q: QVal, F: PrefixFamily> Quantity<Q, F> {
             ..self
         }
     }
+
+    /// Change whether values of integer types are treated as integral, so they
+    /// display exactly when they do not need a prefix.  Floating-point values are
+    /// still displayed with significant figures.
+    ///
+    /// ```
+    /// # use friendly::quantity::Quantity;
+    /// let q = Quantity::decimal(532).suffix("B").auto_integral(true);
+    /// assert_eq!(q.to_string().as_str(), "532 B");
+    /// let q = Quantity::decimal(532.0).suffix("B").auto_integral(true);
+    /// assert_eq!(q.to_string().as_str(), "532.0 B");
+    /// ```
+    pub fn auto_integral(self, auto_int: bool) -> Self {
+        Quantity { auto_int, ..self }
+    }
+
+    /// Get the quantity's value as a floating-point number.
+    pub(crate) fn as_float(&self) -> f64 {
+        self.value.as_float()
+    }
+
+    /// Query whether this quantity's value is integral.
+    fn is_integral(&self) -> bool {
+        self.integral || (self.auto_int && self.value.is_integer())
+    }
+
+    /// Change whether auto-scaling may pick minor prefixes (centi, deci, deca, and
+    /// hecto).  These are excluded by default.
+    ///
+    /// ```
+    /// # use friendly::scalar;
+    /// let q = scalar(0.05).suffix("m").allow_minor_prefixes(true);
+    /// assert_eq!(q.to_string().as_str(), "5.000 cm");
+    /// ```
+    pub fn allow_minor_prefixes(self, minor: bool) -> Self {
+        Quantity { minor, ..self }
+    }
+
+    /// Change how prefix labels are written.  Labels are strict SI and IEC by
+    /// default; informal styles must be requested explicitly.
+    ///
+    /// ```
+    /// # use friendly::{bytes, scalar};
+    /// # use friendly::scale::PrefixStyle;
+    /// let q = scalar(12_900).suffix("B").prefix_style(PrefixStyle::UpperKilo);
+    /// assert_eq!(q.to_string().as_str(), "12.90 KB");
+    /// let q = bytes(13200).prefix_style(PrefixStyle::Informal);
+    /// assert_eq!(q.to_string().as_str(), "12.89 KB");
+    /// ```
+    pub fn prefix_style(self, pfx_style: PrefixStyle) -> Self {
+        Quantity { pfx_style, ..self }
+    }
+
+    /// Change how digits are grouped in the displayed number.
+    ///
+    /// ```
+    /// # use friendly::{integer, Grouping};
+    /// # use friendly::scale::*;
+    /// let q = integer(1234567).scale(Decimal::UNIT).group(Grouping::Comma);
+    /// assert_eq!(q.to_string().as_str(), "1,234,567");
+    /// ```
+    ///
+    /// Grouping also applies to scaled values, and `true` is shorthand for commas:
+    ///
+    /// ```
+    /// # use friendly::scalar;
+    /// # use friendly::scale::*;
+    /// let q = scalar(1_532_000).scale(Decimal::KILO).group(true);
+    /// assert_eq!(q.to_string().as_str(), "1,532 k");
+    /// ```
+    pub fn group<G: Into<Grouping>>(self, group: G) -> Self {
+        Quantity {
+            group: group.into(),
+            ..self
+        }
+    }
+
+    /// Change the decimal separator (e.g. to `,` for many European locales).
+    ///
+    /// ```
+    /// # use friendly::{bytes, Grouping};
+    /// let q = bytes(13200).decimal_separator(',');
+    /// assert_eq!(q.to_string().as_str(), "12,89 KiB");
+    /// ```
+    pub fn decimal_separator(self, point: char) -> Self {
+        Quantity { point, ..self }
+    }
+
+    /// Change whether to show a plus sign on positive values.
+    ///
+    /// ```
+    /// # use friendly::bytes;
+    /// let q = bytes(1_258_291).sig_figs(2).force_sign(true);
+    /// assert_eq!(q.to_string().as_str(), "+1.2 MiB");
+    /// ```
+    pub fn force_sign(self, sign: bool) -> Self {
+        Quantity { sign, ..self }
+    }
+
+    /// Change how the quantity's magnitude is expressed.  Notations other than
+    /// [Notation::Prefix] display an exponent in place of the scale's prefix.
+    ///
+    /// ```
+    /// # use friendly::bytes;
+    /// # use friendly::Notation;
+    /// let q = bytes(13200).notation(Notation::Scientific);
+    /// assert_eq!(q.to_string().as_str(), "1.320 × 10⁴ B");
+    /// assert_eq!(q.ascii(true).to_string().as_str(), "1.320e4 B");
+    /// ```
+    pub fn notation(self, notation: Notation) -> Self {
+        Quantity { notation, ..self }
+    }
+
+    /// Change whether to write ASCII-only output, for logs and terminals that do not
+    /// handle UTF-8.
+    ///
+    /// Exponents and unit powers are written as “1.320e4” and “m^2” instead of with
+    /// superscripts, “u” is written for the micro prefix, and Unicode punctuation is
+    /// replaced with ASCII equivalents:
+    ///
+    /// ```
+    /// # use friendly::scalar;
+    /// let q = scalar(0.0000123).suffix("s").uncertainty(0.0000004);
+    /// assert_eq!(q.to_string().as_str(), "12.3 ± 0.4 μs");
+    /// assert_eq!(q.ascii(true).to_string().as_str(), "12.3 +/- 0.4 us");
+    /// ```
+    ///
+    /// The default can be changed crate-wide with [Config::ascii](crate::config::Config::ascii).
+    pub fn ascii(self, ascii: bool) -> Self {
+        Quantity { ascii, ..self }
+    }
+
+    /// Lay out the quantity with a template instead of the usual “number space
+    /// prefix suffix” order.
+    ///
+    /// The template can contain the placeholders `{value}` (the number, with any sign,
+    /// uncertainty, and exponent), `{prefix}`, `{unit}`, `{space}`, and `{nbsp}` (a
+    /// non-breaking space); `{{` and `}}` write literal braces.  Other text is written
+    /// as-is, so units can go before numbers or the prefix can be wrapped in markup:
+    ///
+    /// ```
+    /// # use friendly::bytes;
+    /// let q = bytes(13200).template("{value}{nbsp}<b>{prefix}</b>{unit}");
+    /// assert_eq!(q.to_string().as_str(), "12.89\u{a0}<b>Ki</b>B");
+    /// let q = friendly::scalar(42.5).suffix("$").template("{unit}{value}");
+    /// assert_eq!(q.to_string().as_str(), "$42.50");
+    /// ```
+    ///
+    /// For layouts a template cannot express, use [Quantity::to_parts].
+    pub fn template<S: Into<Cow<'static, str>>>(self, template: S) -> Self {
+        Quantity {
+            template: Some(template.into()),
+            ..self
+        }
+    }
 }
 
-impl<Q: QVal, F: PrefixFamily> fmt::Display for Quantity<Q, F> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let scaled = match self.scale {
-            Scale::Native => None,
-            Scale::Auto => Some(F::autoscale(self.value.as_float())),
-            Scale::Fixed(s) => Some((s.scale_value(self.value.as_float()), s)),
+impl<Q: QVal, F: PrefixFamily> Quantity<Q, F> {
+    /// Get a display threshold as a quantity with the same options.
+    fn threshold(&self, value: f64) -> Quantity<f64, F> {
+        Quantity {
+            value,
+            scale: self.scale,
+            sfx_str: self.sfx_str.clone(),
+            sfx_pow: self.sfx_pow,
+            sfx_words: self.sfx_words.clone(),
+            nsig: self.nsig,
+            spc: self.spc,
+            integral: false,
+            auto_int: false,
+            minor: self.minor,
+            pfx_style: self.pfx_style,
+            group: self.group,
+            point: self.point,
+            notation: self.notation,
+            ascii: self.ascii,
+            sign: false,
+            decimals: self.decimals,
+            trim: true,
+            rounding: self.rounding,
+            err: None,
+            min_disp: None,
+            max_disp: None,
+            non_finite: None,
+            zero: None,
+            template: self.template.clone(),
+        }
+    }
+
+    /// Query whether this quantity is written in ASCII only.
+    pub(crate) fn is_ascii(&self) -> bool {
+        self.ascii
+    }
+
+    /// Resolve the scaled value to display, with its precision and prefix.
+    ///
+    /// Returns `None` if the value should be displayed as-is.
+    pub(crate) fn resolve(&self) -> Option<(f64, usize, F::Prefix)> {
+        self.resolve_in(&self.scale)
+    }
+
+    /// Resolve the scaled value to display in a specific scale.
+    pub(crate) fn resolve_in(&self, scale: &Scale<F>) -> Option<(f64, usize, F::Prefix)> {
+        let pfxs = if self.minor {
+            F::all_prefixes_with_minor()
+        } else {
+            F::all_prefixes()
+        };
+        let val = self.value.as_float();
+        let autoscale = |pfxs: &[&F::Prefix]| self.autoscale_among(val, pfxs);
+        let (scaled, auto) = match *scale {
+            Scale::Native => (None, None),
+            Scale::Auto => (Some(autoscale(pfxs)), Some(pfxs)),
+            Scale::AutoRange(lo, hi) => {
+                let pfxs = prefix_range(pfxs, &lo, &hi);
+                (Some(autoscale(pfxs)), Some(pfxs))
+            }
+            Scale::AutoWithin(pfxs) => (Some(autoscale(pfxs)), Some(pfxs)),
+            Scale::Fixed(s) => (Some((self.rescale(&s, val), s)), None),
         };
         // don't rescale unscaled integral values
-        let scaled = scaled.filter(|(_v, pfx)| pfx.exponent() != 0 || !self.integral);
-        if let Some((sv, scale)) = scaled {
-            let (sv, prec) = sigscale(sv, self.nsig as usize);
-            write!(f, "{:.*}", prec, sv)?;
-            let sl = scale.label();
-            if self.spc && (!sl.is_empty() || !self.sfx_str.is_empty()) {
-                write!(f, " ")?;
+        let scaled = scaled.filter(|(_v, pfx)| pfx.exponent() != 0 || !self.is_integral());
+        scaled.map(|(sv, pfx)| {
+            let (sv, prec) = self.round(sv);
+            // rounding may reach the next prefix (1023.97 KiB is 1.000 MiB)
+            let rounded = sv.abs() * self.factor(&pfx);
+            let next = auto.and_then(|pfxs| {
+                pfxs.iter()
+                    .filter(|p| self.factor(p) > self.factor(&pfx) && rounded >= self.factor(p))
+                    .min_by(|a, b| self.factor(a).total_cmp(&self.factor(b)))
+            });
+            match next {
+                Some(next) => {
+                    let (sv, prec) = self.round(self.rescale(*next, sv * self.factor(&pfx)));
+                    (sv, prec, **next)
+                }
+                None => (sv, prec, pfx),
             }
-            write!(f, "{}{}", sl, self.sfx_str)?;
+        })
+    }
+
+    /// Get the power that prefixes are raised to: the unit's power, unless the power
+    /// belongs to the denominator of a compound unit.
+    fn prefix_power(&self) -> i32 {
+        if self.sfx_pow == 0 || self.sfx_str.contains('/') {
+            1
         } else {
-            write!(f, "{}", self.value)?;
-            if self.spc && !self.sfx_str.is_empty() {
-                write!(f, " ")?;
+            self.sfx_pow
+        }
+    }
+
+    /// Get the factor a prefix scales this quantity's values by.
+    fn factor(&self, pfx: &F::Prefix) -> f64 {
+        pfx.multiplier().powi(self.prefix_power())
+    }
+
+    /// Rescale a value for a prefix.
+    fn rescale(&self, pfx: &F::Prefix, val: f64) -> f64 {
+        val / self.factor(pfx)
+    }
+
+    /// Auto-scale a value to the best-fitting prefix in a list, accounting for the
+    /// prefix power.
+    fn autoscale_among(&self, val: f64, pfxs: &[&F::Prefix]) -> (f64, F::Prefix) {
+        if self.prefix_power() == 1 {
+            return F::autoscale_among(val, pfxs);
+        } else if !val.is_normal() || pfxs.is_empty() {
+            return (val, F::unit_prefix());
+        }
+        // with negative powers, larger prefixes scale values up, so compare factors
+        let by_factor =
+            |a: &&&F::Prefix, b: &&&F::Prefix| self.factor(a).total_cmp(&self.factor(b));
+        let pfx = pfxs
+            .iter()
+            .filter(|p| self.rescale(p, val).abs() >= 1.0)
+            .max_by(by_factor)
+            .or_else(|| pfxs.iter().min_by(by_factor))
+            .unwrap();
+        (self.rescale(pfx, val), **pfx)
+    }
+
+    /// Round a scaled value for display, returning it with its precision.
+    fn round(&self, val: f64) -> (f64, usize) {
+        match self.decimals {
+            Some(d) => decscale_with(val, d, self.rounding),
+            None => sigscale_with(val, self.nsig as usize, self.rounding),
+        }
+    }
+
+    /// Resolve the mantissa, precision, and exponent to display in exponent notation.
+    ///
+    /// Returns `None` if the quantity uses prefixes or should be displayed as-is.
+    fn resolve_exponent(&self) -> Option<(f64, usize, i32)> {
+        let split = self
+            .notation
+            .split(self.value.as_float(), |v| self.round(v));
+        // don't rewrite integral values that need no exponent
+        split.filter(|(_m, _prec, exp)| *exp != 0 || !self.is_integral())
+    }
+
+    /// Write the display text directly to an output, such as a reused
+    /// [FixedBuf](crate::buffer::FixedBuf).  This does not allocate.
+    ///
+    /// Unlike [Display](fmt::Display), this ignores padding and alignment.
+    pub fn write_to<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+        if self.ascii {
+            self.write_display(&mut AsciiWriter::new(out))
+        } else {
+            self.write_display(out)
+        }
+    }
+
+    /// Write the display text, before any transliteration to ASCII.
+    fn write_display<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+        let val = self.value.as_float();
+        if let (Some([nan, inf, neg_inf]), false) = (&self.non_finite, val.is_finite()) {
+            return out.write_str(match val {
+                v if v.is_nan() => nan,
+                v if v > 0.0 => inf,
+                _ => neg_inf,
+            });
+        }
+        if let (Some(text), true) = (&self.zero, val == 0.0) {
+            return out.write_str(text);
+        }
+        if let Some(min) = self.min_disp.filter(|m| val != 0.0 && val.abs() < *m) {
+            out.write_str(if val < 0.0 { ">-" } else { "<" })?;
+            return self.threshold(min).write_display(out);
+        }
+        if let Some(max) = self.max_disp.filter(|m| val.abs() > *m) {
+            out.write_str(if val < 0.0 { "<-" } else { ">" })?;
+            return self.threshold(max).write_display(out);
+        }
+        match &self.template {
+            Some(tmpl) => {
+                let mut num = StackBuf::new();
+                let form = self.write_value(&mut num, &self.scale)?;
+                let mut unit = StackBuf::new();
+                self.write_suffix(&mut unit, &form)?;
+                write_template(out, tmpl, num.as_str(), form.label, unit.as_str())
+            }
+            None => {
+                let form = self.write_value(out, &self.scale)?;
+                self.write_unit(out, &form)
             }
-            write!(f, "{}", self.sfx_str)?;
+        }
+    }
+
+    /// Resolve the components to display in a scale.
+    fn layout(&self, scale: &Scale<F>) -> Layout {
+        let val = self.value.as_float();
+        let err = self.err.filter(|e| e.is_normal());
+        let mut layout = Layout {
+            num: None,
+            err: None,
+            exp: None,
+            label: "",
+            word: false,
+            pow: None,
+        };
+        if self.notation != Notation::Prefix {
+            if let Some((m, prec, e)) = self.resolve_exponent() {
+                layout.num = Some((m, prec));
+                layout.exp = Some(e);
+            }
+        } else if let Some((sv, prec, pfx)) = self.resolve_in(scale) {
+            if let Some(e) = err {
+                // the uncertainty, not the significant figures, sets the rounding
+                let (v, e, prec) = errscale(self.rescale(&pfx, val), self.rescale(&pfx, e));
+                layout.num = Some((v, prec));
+                layout.err = Some(e);
+            } else {
+                layout.num = Some((sv, prec));
+            }
+            layout.label = self.pfx_style.label(&pfx);
+            layout.word = pfx.is_word();
+            let exp = pfx.exponent() * self.prefix_power();
+            layout.pow = u128::try_from(pfx.base()).ok().map(|b| (b, exp));
+        } else if let Some(e) = err {
+            let (v, e, prec) = errscale(val, e);
+            layout.num = Some((v, prec));
+            layout.err = Some(e);
+        }
+        if let (true, None, Some((v, prec))) = (self.trim, layout.err, layout.num) {
+            layout.num = Some((v, trimmed_precision(v, prec)));
+        }
+        layout
+    }
+
+    /// Get the components of the displayed quantity, so the number and unit can be
+    /// presented separately.
+    ///
+    /// ```
+    /// # use friendly::bytes;
+    /// let q = bytes(13200);
+    /// let parts = q.to_parts();
+    /// assert_eq!(parts.mantissa, 12.89);
+    /// assert_eq!(parts.precision, 2);
+    /// assert_eq!(parts.prefix_label, "Ki");
+    /// assert_eq!(parts.suffix, "B");
+    /// ```
+    pub fn to_parts(&self) -> Parts<'_> {
+        let layout = self.layout(&self.scale);
+        let (mantissa, precision) = layout.num.unwrap_or_else(|| {
+            // unscaled values are displayed as-is, so count their decimal places
+            let mut num = StackBuf::new();
+            let _ = write!(num, "{}", self.value);
+            let prec = num
+                .as_str()
+                .find('.')
+                .map_or(0, |i| num.as_str().len() - i - 1);
+            (self.value.as_float(), prec)
+        });
+        let one = mantissa.abs() == 1.0 && precision == 0 && layout.exp.is_none();
+        Parts {
+            mantissa,
+            precision,
+            prefix_label: layout.label,
+            suffix: self.suffix_text(layout.label, one),
+            suffix_power: self.sfx_pow,
+            exponent: layout.exp,
+            uncertainty: layout.err,
+        }
+    }
+
+    /// Write the number (with any sign, uncertainty, and exponent) in a scale,
+    /// returning how to write its unit.
+    pub(crate) fn write_value<W: fmt::Write>(
+        &self,
+        out: &mut W,
+        scale: &Scale<F>,
+    ) -> Result<UnitForm, fmt::Error> {
+        let layout = self.layout(scale);
+        let mut num = StackBuf::new();
+        match layout.num {
+            Some((v, prec)) => match self.exact_digits(&layout, prec) {
+                Some((neg, n)) => write_int_fixed(&mut num, neg, n, prec)?,
+                None => write_fixed(&mut num, v, prec)?,
+            },
+            None => write!(num, "{}", self.value)?,
+        }
+        if self.sign && self.value.as_float() > 0.0 {
+            out.write_char('+')?;
+        }
+        write_number(out, num.as_str(), self.group.separator(), self.point)?;
+        if let (Some(e), Some((_, prec))) = (layout.err, layout.num) {
+            let mut err_num = StackBuf::new();
+            write_fixed(&mut err_num, e, prec)?;
+            out.write_str(" ± ")?;
+            write_number(out, err_num.as_str(), self.group.separator(), self.point)?;
+        }
+        if let Some(e) = layout.exp {
+            write_exponent(out, e, self.ascii)?;
+        }
+        let one = layout.err.is_none()
+            && layout.exp.is_none()
+            && num.as_str().trim_start_matches('-') == "1";
+        Ok(UnitForm {
+            label: layout.label,
+            word: layout.word,
+            one,
+        })
+    }
+
+    /// Compute the displayed digits (the rounded number times 10^prec) in integer
+    /// arithmetic, for exact decimals and for integers too large to be exact as floats.
+    fn exact_digits(&self, layout: &Layout, prec: usize) -> Option<(bool, u128)> {
+        let (neg, mag, places) = self.value.as_exact_decimal()?;
+        if (places == 0 && mag <= 1 << 53) || layout.err.is_some() || layout.exp.is_some() {
+            return None;
+        }
+        let (base, exp) = layout.pow?;
+        let mult = base.checked_pow(exp.unsigned_abs())?;
+        let mut num = mag.checked_mul(10u128.checked_pow(prec as u32)?)?;
+        let mut den = 10u128.checked_pow(places)?;
+        if exp >= 0 {
+            den = den.checked_mul(mult)?;
+        } else {
+            num = num.checked_mul(mult)?;
+        }
+        if self.decimals.is_none() && prec == 0 {
+            // round large integers to the significant figures, like the float path
+            let digits = (num / den).checked_ilog10().map_or(1, |d| d + 1);
+            if let Some(drop) = digits.checked_sub(self.nsig).filter(|d| *d > 0) {
+                let unit = 10u128.pow(drop);
+                let n = div_round(num, den.checked_mul(unit)?, neg, self.rounding);
+                return Some((neg, n.checked_mul(unit)?));
+            }
+        }
+        Some((neg, div_round(num, den, neg, self.rounding)))
+    }
+
+    /// Write the prefix label and unit suffix.
+    pub(crate) fn write_unit<W: fmt::Write>(&self, out: &mut W, form: &UnitForm) -> fmt::Result {
+        let sfx = self.suffix_text(form.label, form.one);
+        if let Some(spc) = self.spc.text() {
+            if !form.label.is_empty() || !sfx.is_empty() {
+                out.write_str(spc)?;
+            }
+        }
+        out.write_str(form.label)?;
+        if form.word && !form.label.is_empty() && !sfx.is_empty() {
+            out.write_char(' ')?;
+        }
+        self.write_suffix(out, form)
+    }
+
+    /// Get the unit suffix to write with a prefix label.
+    fn suffix_text(&self, label: &str, one: bool) -> &str {
+        match &self.sfx_words {
+            // unit words are for unscaled values, or when there is no symbol
+            Some((sing, plural)) if label.is_empty() || self.sfx_str.is_empty() => {
+                if one {
+                    sing
+                } else {
+                    plural
+                }
+            }
+            _ => &self.sfx_str,
+        }
+    }
+
+    /// Write the unit suffix with its power.
+    fn write_suffix<W: fmt::Write>(&self, out: &mut W, form: &UnitForm) -> fmt::Result {
+        let sfx = self.suffix_text(form.label, form.one);
+        out.write_str(sfx)?;
+        if self.sfx_pow != 1 && !sfx.is_empty() {
+            write_power(out, self.sfx_pow, self.ascii)?;
         }
         Ok(())
     }
 }
 
+/// Write a quantity's components with a layout template.
+fn write_template<W: fmt::Write>(
+    out: &mut W,
+    tmpl: &str,
+    value: &str,
+    prefix: &str,
+    unit: &str,
+) -> fmt::Result {
+    let mut rest = tmpl;
+    while let Some(i) = rest.find(['{', '}']) {
+        out.write_str(&rest[..i])?;
+        rest = &rest[i..];
+        let (text, len) = if rest.starts_with("{{") {
+            ("{", 2)
+        } else if rest.starts_with("}}") {
+            ("}", 2)
+        } else {
+            match rest.find('}').map(|j| &rest[..=j]) {
+                Some("{value}") => (value, 7),
+                Some("{prefix}") => (prefix, 8),
+                Some("{unit}") => (unit, 6),
+                Some("{space}") => (" ", 7),
+                Some("{nbsp}") => ("\u{a0}", 6),
+                // not a placeholder, so write the brace as-is
+                _ => (&rest[..1], 1),
+            }
+        };
+        out.write_str(text)?;
+        rest = &rest[len..];
+    }
+    out.write_str(rest)
+}
+
+/// How to write the unit after a displayed number.
+pub(crate) struct UnitForm {
+    /// The prefix label.
+    pub(crate) label: &'static str,
+    /// Whether the prefix is a word.
+    pub(crate) word: bool,
+    /// Whether the number is displayed as exactly one.
+    pub(crate) one: bool,
+}
+
+/// The resolved components of a displayed quantity.
+struct Layout {
+    /// The rounded number and its precision, or `None` to display the value as-is.
+    num: Option<(f64, usize)>,
+    err: Option<f64>,
+    exp: Option<i32>,
+    label: &'static str,
+    word: bool,
+    /// The prefix base and exponent, for exact scaling.
+    pow: Option<(u128, i32)>,
+}
+
+/// The components of a displayed quantity (from [Quantity::to_parts]).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Parts<'a> {
+    /// The displayed number, after scaling and rounding.
+    pub mantissa: f64,
+    /// The number of digits displayed after the decimal point.
+    pub precision: usize,
+    /// The prefix label (empty if unscaled).
+    pub prefix_label: &'static str,
+    /// The unit suffix.
+    pub suffix: &'a str,
+    /// The power of the unit suffix (1 unless set with [Quantity::suffix_pow]).
+    pub suffix_power: i32,
+    /// The power-of-10 exponent, when displayed in exponent notation.
+    pub exponent: Option<i32>,
+    /// The rounded uncertainty, if one is displayed.
+    pub uncertainty: Option<f64>,
+}
+
+/// A machine-readable description of a displayed quantity (with the `serde` feature).
+///
+/// This is obtained with [Quantity::structured], and serializes to a structure like:
+///
+/// ```json
+/// { "value": 13200, "scaled": 12.89, "prefix": "Ki", "unit": "B", "display": "12.89 KiB" }
+/// ```
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Structured<'a, Q> {
+    /// The original value.
+    pub value: &'a Q,
+    /// The scaled value, rounded as displayed.
+    pub scaled: f64,
+    /// The prefix label (empty if unscaled).
+    pub prefix: &'static str,
+    /// The power-of-10 exponent, when displayed in exponent notation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exponent: Option<i32>,
+    /// The unit suffix.
+    pub unit: &'a str,
+    /// The display string.
+    pub display: String,
+}
+
+#[cfg(feature = "serde")]
+impl<Q: QVal, F: PrefixFamily> Quantity<Q, F> {
+    /// Get a machine-readable description of this quantity alongside its display.
+    pub fn structured(&self) -> Structured<'_, Q> {
+        let parts = self.to_parts();
+        Structured {
+            value: &self.value,
+            scaled: parts.mantissa,
+            prefix: parts.prefix_label,
+            exponent: parts.exponent,
+            unit: parts.suffix,
+            display: self.to_string(),
+        }
+    }
+}
+
+impl<Q: QVal, F: PrefixFamily> fmt::Display for Quantity<Q, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = StackBuf::new();
+        self.write_to(&mut buf)?;
+        buf.pad(f)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::Quantity;
+    use crate::grouping::Grouping;
+    use crate::notation::Notation;
     use crate::scale::*;
+    use crate::sigfig::Rounding;
 
     #[test]
     fn test_decimal_quantity() {
@@ -178,12 +1302,49 @@ mod test {
         assert_eq!(tq.to_string().as_str(), "0.00 B");
     }
 
+    #[test]
+    fn test_zero_auto_integral() {
+        let tq = Quantity::decimal(0).suffix("B").auto_integral(true);
+        assert_eq!(tq.to_string().as_str(), "0 B");
+        let tq = Quantity::decimal(15_250).suffix("B").auto_integral(true);
+        assert_eq!(tq.to_string().as_str(), "15.25 kB");
+    }
+
     #[test]
     fn test_megawatts() {
         let tq = Quantity::decimal(15_250_000.0).suffix("W");
         assert_eq!(tq.to_string().as_str(), "15.25 MW");
     }
 
+    #[test]
+    fn test_rounding_promotes_prefix() {
+        let tq = Quantity::binary(1023.97 * 1024.0).suffix("B");
+        assert_eq!(tq.to_string().as_str(), "1.000 MiB");
+        let tq = Quantity::decimal(999_960).suffix("W");
+        assert_eq!(tq.to_string().as_str(), "1.000 MW");
+        let tq = Quantity::decimal(999_960).scale(Decimal::KILO).suffix("W");
+        assert_eq!(tq.to_string().as_str(), "1000.0 kW");
+    }
+
+    #[test]
+    fn test_uncertainty() {
+        let tq = Quantity::decimal(12.345).uncertainty(0.14).suffix("s");
+        assert_eq!(tq.to_string().as_str(), "12.35 ± 0.14 s");
+        let tq = Quantity::decimal(1234).integral(true).uncertainty(43.0);
+        assert_eq!(tq.scale(Decimal::UNIT).to_string().as_str(), "1230 ± 40");
+    }
+
+    #[test]
+    fn test_parts_native() {
+        let tq = Quantity::decimal(1234567.25).scale(Scale::<Decimal>::Native);
+        let tq = tq.suffix("m");
+        let parts = tq.to_parts();
+        assert_eq!(parts.mantissa, 1234567.25);
+        assert_eq!(parts.precision, 2);
+        assert_eq!(parts.prefix_label, "");
+        assert_eq!(parts.suffix, "m");
+    }
+
     #[test]
     fn test_kibibytes_ps() {
         let tq = Quantity::binary(182_421.0).suffix("B/s");
@@ -201,4 +1362,387 @@ mod test {
         let tq = Quantity::decimal(0.023477).suffix("s");
         assert_eq!(tq.to_string().as_str(), "23.48 ms");
     }
+
+    #[test]
+    fn test_group_scaled() {
+        let tq = Quantity::decimal(1_532_000).scale(Decimal::UNIT);
+        let tq = tq.group(Grouping::Period);
+        assert_eq!(tq.to_string().as_str(), "1.532.000");
+    }
+
+    #[test]
+    fn test_group_native_float() {
+        let tq = Quantity::decimal(1234567.25).scale(Scale::<Decimal>::Native);
+        let tq = tq.suffix("m").group(Grouping::Space);
+        assert_eq!(tq.to_string().as_str(), "1 234 567.25 m");
+    }
+
+    #[test]
+    fn test_group_prefixed_mantissa() {
+        let tq = Quantity::decimal(1_532_400).scale(Decimal::KILO);
+        let tq = tq.sig_figs(6).group(true).suffix("W");
+        assert_eq!(tq.to_string().as_str(), "1,532.40 kW");
+    }
+
+    #[test]
+    fn test_decimals_small() {
+        let tq = Quantity::decimal(0.023477).suffix("s").decimals(1);
+        assert_eq!(tq.to_string().as_str(), "23.5 ms");
+        let tq = tq.sig_figs(2);
+        assert_eq!(tq.to_string().as_str(), "23 ms");
+    }
+
+    #[test]
+    fn test_rounding_half_even() {
+        let tq = Quantity::decimal(2.5).scale(Decimal::UNIT).sig_figs(1);
+        assert_eq!(tq.clone().to_string().as_str(), "3");
+        let tq = tq.rounding(Rounding::HalfEven);
+        assert_eq!(tq.to_string().as_str(), "2");
+    }
+
+    #[test]
+    fn test_padding() {
+        let tq = Quantity::binary(182_421.0).suffix("B");
+        assert_eq!(format!("[{:>12}]", tq).as_str(), "[   178.1 KiB]");
+        assert_eq!(format!("[{:*<11}]", tq).as_str(), "[178.1 KiB**]");
+    }
+
+    #[test]
+    fn test_precision_ignored() {
+        // the precision is not a maximum width
+        let tq = Quantity::binary(13200).suffix("B");
+        assert_eq!(format!("{:.2}", tq).as_str(), "12.89 KiB");
+        assert_eq!(format!("[{:>10.2}]", tq).as_str(), "[ 12.89 KiB]");
+    }
+
+    #[test]
+    fn test_group_small() {
+        let tq = Quantity::binary(182_421.0)
+            .suffix("B")
+            .group(Grouping::Comma);
+        assert_eq!(tq.to_string().as_str(), "178.1 KiB");
+    }
+
+    #[test]
+    fn test_scale_range_floor() {
+        let tq = Quantity::binary(300).scale_range(Binary::KIBI..=Binary::GIBI);
+        assert_eq!(tq.suffix("B").to_string().as_str(), "0.2930 KiB");
+    }
+
+    #[test]
+    fn test_jedec_fixed() {
+        let tq = Quantity::binary(3 << 20).scale(Binary::KIBI).jedec();
+        assert_eq!(tq.to_string().as_str(), "3072 K");
+    }
+
+    #[test]
+    fn test_jedec_within() {
+        const PFXS: &[&Binary] = &[&Binary::KIBI, &Binary::GIBI];
+        // 300 MiB is below the GiB floor, so it stays in KiB
+        let tq = Quantity::binary(300 << 20)
+            .scale(Scale::AutoWithin(PFXS))
+            .suffix("B")
+            .jedec();
+        assert_eq!(tq.to_string().as_str(), "307200 KB");
+    }
+
+    #[test]
+    fn test_word_scale_suffix() {
+        let tq = Quantity::decimal(1_500_000)
+            .scale(Words::AUTO)
+            .suffix("people");
+        assert_eq!(tq.to_string().as_str(), "1.500 million people");
+    }
+
+    #[test]
+    fn test_decimal_comma_grouped() {
+        let tq = Quantity::decimal(1234567.25).scale(Scale::<Decimal>::Native);
+        let tq = tq.group(Grouping::Period).decimal_separator(',');
+        assert_eq!(tq.to_string().as_str(), "1.234.567,25");
+    }
+
+    #[test]
+    fn test_decimal_comma_small() {
+        let tq = Quantity::decimal(0.023477).scale(Decimal::UNIT);
+        assert_eq!(tq.decimal_separator(',').to_string().as_str(), "0,02348");
+    }
+
+    #[test]
+    fn test_force_sign() {
+        let tq = Quantity::decimal(-1500).suffix("W").force_sign(true);
+        assert_eq!(tq.to_string().as_str(), "-1.500 kW");
+        let tq = Quantity::decimal(0).integral(true).force_sign(true);
+        assert_eq!(tq.to_string().as_str(), "0");
+    }
+
+    #[test]
+    fn test_scientific_small() {
+        let tq = Quantity::decimal(0.000_234_77).suffix("s");
+        let tq = tq.notation(Notation::Scientific);
+        assert_eq!(tq.to_string().as_str(), "2.348 × 10⁻⁴ s");
+        assert_eq!(tq.ascii(true).to_string().as_str(), "2.348e-4 s");
+    }
+
+    #[test]
+    fn test_engineering() {
+        let tq = Quantity::decimal(13200).notation(Notation::Engineering);
+        assert_eq!(tq.to_string().as_str(), "13.20 × 10³");
+        let tq = Quantity::decimal(0.0047).suffix("F").ascii(true);
+        let tq = tq.notation(Notation::Engineering);
+        assert_eq!(tq.to_string().as_str(), "4.700e-3 F");
+    }
+
+    #[test]
+    fn test_scientific_integral() {
+        let tq = Quantity::decimal(5).integral(true).suffix("B");
+        let tq = tq.notation(Notation::Scientific);
+        assert_eq!(tq.to_string().as_str(), "5 B");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_structured() {
+        let tq = Quantity::binary(13200).suffix("B");
+        let json = serde_json::to_value(tq.structured()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "value": 13200,
+                "scaled": 12.89,
+                "prefix": "Ki",
+                "unit": "B",
+                "display": "12.89 KiB",
+            })
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_structured_native() {
+        let tq = Quantity::decimal(532).integral(true).suffix("B");
+        let st = tq.structured();
+        assert_eq!(st.scaled, 532.0);
+        assert_eq!(st.prefix, "");
+        assert_eq!(st.display.as_str(), "532 B");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_structured_scientific() {
+        let tq = Quantity::binary(13200).suffix("B");
+        let tq = tq.notation(Notation::Scientific);
+        let st = tq.structured();
+        assert_eq!(st.scaled, 1.32);
+        assert_eq!(st.exponent, Some(4));
+        assert_eq!(st.display.as_str(), "1.320 × 10⁴ B");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_number() {
+        let n: serde_json::Number = serde_json::from_str("13200").unwrap();
+        let tq = Quantity::binary(n).suffix("B");
+        assert_eq!(tq.to_string().as_str(), "12.89 KiB");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_float() {
+        let n: serde_json::Number = serde_json::from_str("0.023477").unwrap();
+        let tq = Quantity::decimal(n).suffix("s");
+        assert_eq!(tq.to_string().as_str(), "23.48 ms");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_exact_native() {
+        let n = serde_json::Number::from(u64::MAX);
+        let tq = Quantity::decimal(n).scale(Scale::<Decimal>::Native);
+        assert_eq!(tq.to_string().as_str(), "18446744073709551615");
+    }
+
+    #[test]
+    fn test_narrow_space() {
+        let tq = Quantity::binary(13200).suffix("B");
+        let tq = tq.space(crate::SpaceStyle::NarrowNbsp);
+        assert_eq!(tq.to_string().as_str(), "12.89\u{202f}KiB");
+        assert_eq!(tq.ascii(true).to_string().as_str(), "12.89 KiB");
+        let tq = Quantity::decimal(5).space(crate::SpaceStyle::Nbsp);
+        assert_eq!(tq.to_string().as_str(), "5.000");
+    }
+
+    #[test]
+    fn test_suffix_plural() {
+        let tq = Quantity::decimal(1.0).suffix_plural("second", "seconds");
+        assert_eq!(tq.to_string().as_str(), "1.000 seconds");
+        let tq = Quantity::decimal(-1)
+            .integral(true)
+            .suffix_plural("step", "steps");
+        assert_eq!(tq.to_string().as_str(), "-1 step");
+        let tq = tq.template("{value}:{unit}");
+        assert_eq!(tq.to_string().as_str(), "-1:step");
+        let tq = Quantity::decimal(1).integral(true).suffix_plural("B", "Bs");
+        let parts = tq.to_parts();
+        assert_eq!(parts.suffix, "B");
+        let tq = Quantity::decimal(2_500_000).scale(Words::AUTO);
+        let tq = tq.suffix_plural("person", "people");
+        assert_eq!(tq.to_string().as_str(), "2.500 million people");
+    }
+
+    #[test]
+    fn test_trim_zeros() {
+        let tq = Quantity::decimal(1_204_000).suffix("W").trim_zeros(true);
+        assert_eq!(tq.to_string().as_str(), "1.204 MW");
+        let tq = tq.notation(Notation::Scientific);
+        assert_eq!(tq.to_string().as_str(), "1.204 × 10⁶ W");
+        let tq = Quantity::decimal(1_200_000).notation(Notation::Scientific);
+        assert_eq!(tq.trim_zeros(true).to_string().as_str(), "1.2 × 10⁶");
+        let tq = Quantity::decimal(0.5)
+            .scale(Decimal::UNIT)
+            .decimals(3)
+            .trim_zeros(true);
+        assert_eq!(tq.to_string().as_str(), "0.5");
+        assert_eq!(tq.to_parts().precision, 1);
+    }
+
+    #[test]
+    fn test_min_display() {
+        let tq = Quantity::binary(300).suffix("B").min_display(1024.0);
+        assert_eq!(tq.to_string().as_str(), "<1 KiB");
+        let tq = Quantity::decimal(-0.0004).suffix("V").min_display(0.001);
+        assert_eq!(tq.to_string().as_str(), ">-1 mV");
+        let tq = Quantity::decimal(0).suffix("V").min_display(0.001);
+        assert_eq!(tq.to_string().as_str(), "0.0000 V");
+        let tq = Quantity::decimal(0.000_004)
+            .suffix("s")
+            .min_display(0.00001);
+        assert_eq!(tq.ascii(true).to_string().as_str(), "<10 us");
+    }
+
+    #[test]
+    fn test_max_display() {
+        let tq = Quantity::decimal(-2_500_000).suffix("W").max_display(1e6);
+        assert_eq!(tq.to_string().as_str(), "<-1 MW");
+        let tq = Quantity::decimal(1e6).suffix("W").max_display(1e6);
+        assert_eq!(tq.to_string().as_str(), "1.000 MW");
+    }
+
+    #[test]
+    fn test_non_finite() {
+        let tq = Quantity::decimal(f64::INFINITY).non_finite_each("?", "inf", "-inf");
+        assert_eq!(tq.clone().suffix("W").to_string().as_str(), "inf");
+        let tq = tq.min_display(1.0).template("[{value}]");
+        assert_eq!(tq.to_string().as_str(), "inf");
+        let tq = Quantity::decimal(2.5).suffix("W").non_finite("—");
+        assert_eq!(tq.to_string().as_str(), "2.500 W");
+        let tq = Quantity::decimal(f64::NAN).non_finite(format!("n/{}", "a"));
+        assert_eq!(tq.to_string().as_str(), "n/a");
+    }
+
+    #[test]
+    fn test_zero_display() {
+        let tq = Quantity::binary(0).suffix("B").zero_display("-");
+        assert_eq!(tq.to_string().as_str(), "-");
+        let tq = Quantity::decimal(0.0).zero_display(String::from("none"));
+        assert_eq!(tq.to_string().as_str(), "none");
+        let tq = Quantity::decimal(-0.0).suffix("B").trim_zeros(true);
+        assert_eq!(tq.to_string().as_str(), "-0 B");
+        let tq = Quantity::decimal(0.4).suffix("B").zero_display("-");
+        assert_eq!(tq.to_string().as_str(), "400.0 mB");
+    }
+
+    #[test]
+    fn test_suffix_pow() {
+        let tq = Quantity::decimal(0.0012).suffix_pow("m", 2);
+        assert_eq!(tq.to_string().as_str(), "1200 mm²");
+        let tq = Quantity::decimal(999_999.0).suffix_pow("m", 2);
+        assert_eq!(tq.to_string().as_str(), "1.000 km²");
+        let tq = Quantity::decimal(0.002).suffix_pow("m", -1);
+        assert_eq!(tq.to_string().as_str(), "2.000 km⁻¹");
+        let tq = tq.template("{value}{unit}").ascii(true);
+        assert_eq!(tq.to_string().as_str(), "2.000m^-1");
+        let tq = Quantity::decimal(5.0).suffix_pow("m", 2);
+        let parts = tq.to_parts();
+        assert_eq!(parts.suffix, "m");
+        assert_eq!(parts.suffix_power, 2);
+    }
+
+    #[test]
+    fn test_template_braces() {
+        let tq = Quantity::decimal(1500)
+            .suffix("W")
+            .template("{{{value}}} {prefix}{unit} {x}");
+        assert_eq!(tq.to_string().as_str(), "{1.500} kW {x}");
+    }
+
+    #[test]
+    fn test_template_unscaled() {
+        let tq = Quantity::decimal(12)
+            .integral(true)
+            .suffix("pt")
+            .template(String::from("{unit}:{space}{value}"));
+        assert_eq!(tq.to_string().as_str(), "pt: 12");
+    }
+
+    #[test]
+    fn test_exact_large_integer() {
+        let tq = Quantity::binary((1u128 << 60) + 1)
+            .scale(Binary::KIBI)
+            .decimals(3);
+        assert_eq!(tq.to_string().as_str(), "1125899906842624.001 Ki");
+        let tq = Quantity::decimal(123_456_789_012_345_678_901u128).sig_figs(20);
+        assert_eq!(tq.to_string().as_str(), "123.45678901234567890 E");
+    }
+
+    #[test]
+    fn test_exact_large_negative() {
+        let tq = Quantity::decimal(-9_007_199_254_740_993i64)
+            .scale(Decimal::UNIT)
+            .sig_figs(16);
+        assert_eq!(tq.to_string().as_str(), "-9007199254740993");
+    }
+
+    #[test]
+    fn test_exact_sig_figs() {
+        // integers past 2^53 round to the significant figures like smaller ones
+        let tq = Quantity::decimal(9_007_199_254_740_992u64).scale(Decimal::UNIT);
+        assert_eq!(tq.to_string().as_str(), "9007000000000000");
+        let tq = Quantity::decimal(9_007_199_254_740_993u64).scale(Decimal::UNIT);
+        assert_eq!(tq.to_string().as_str(), "9007000000000000");
+        let tq = Quantity::decimal(9_007_499_254_740_993u64).scale(Decimal::UNIT);
+        assert_eq!(tq.to_string().as_str(), "9007000000000000");
+        let tq = Quantity::decimal(9_007_500_000_000_001u64).scale(Decimal::UNIT);
+        assert_eq!(tq.to_string().as_str(), "9008000000000000");
+        let tq = Quantity::decimal(u64::MAX).scale(Decimal::UNIT);
+        assert_eq!(tq.to_string().as_str(), "18450000000000000000");
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_rust_decimal() {
+        use rust_decimal::Decimal as Dec;
+        // 2.675 is just below 2.675 as a float, so would round down
+        let tq = Quantity::decimal(Dec::new(2675, 3)).decimals(2);
+        assert_eq!(tq.to_string().as_str(), "2.68");
+        let tq = Quantity::decimal(Dec::new(-23_475, 6)).sig_figs(3);
+        assert_eq!(tq.to_string().as_str(), "-23.5 m");
+        let tq = Quantity::decimal(Dec::new(1_234_567_890_123_456_789, 2)).sig_figs(18);
+        assert_eq!(tq.to_string().as_str(), "12.3456789012345679 P");
+    }
+
+    #[cfg(feature = "num-bigint")]
+    #[test]
+    fn test_bigint() {
+        use num_bigint::{BigInt, BigUint};
+        let big: BigUint = "340282366920938463463374607431768211455".parse().unwrap();
+        let tq = Quantity::decimal(big.clone());
+        assert_eq!(tq.to_string().as_str(), "340300000 Q");
+        let tq = Quantity::binary(big * 1024u32 + 1u32).scale(Scale::<Binary>::Native);
+        assert_eq!(
+            tq.to_string().as_str(),
+            "348449143727040986586495598010130648529921"
+        );
+        let tq = Quantity::decimal(BigInt::from(-123_456_789_012_345_678_901i128)).sig_figs(20);
+        assert_eq!(tq.to_string().as_str(), "-123.45678901234567890 E");
+    }
 }
@@ -0,0 +1,112 @@
+//! Display of ranges of quantities.
+use std::fmt;
+
+use crate::ascii::AsciiWriter;
+use crate::buffer::StackBuf;
+use crate::quantity::{QVal, Quantity};
+use crate::scale::{PrefixFamily, Scale};
+
+/// A range of two quantities to display with a shared prefix.
+///
+/// The prefix is chosen for the endpoint with the larger magnitude, and the display
+/// options (suffix, significant figures, etc.) of the high endpoint are used for the
+/// unit.  Ranges whose endpoints display the same are collapsed to a single value:
+///
+/// ```
+/// # use friendly::{bytes, range};
+/// let r = range(bytes(1_258_291).sig_figs(2), bytes(3_565_158).sig_figs(2));
+/// assert_eq!(r.to_string().as_str(), "1.2–3.4 MiB");
+/// let r = range(bytes(1_258_291).sig_figs(2), bytes(1_258_300).sig_figs(2));
+/// assert_eq!(r.to_string().as_str(), "1.2 MiB");
+/// ```
+#[derive(Debug, Clone)]
+pub struct QuantityRange<Q: QVal, F: PrefixFamily> {
+    low: Quantity<Q, F>,
+    high: Quantity<Q, F>,
+    sep: &'static str,
+}
+
+/// Display a range between two quantities.
+pub fn range<Q: QVal, F: PrefixFamily>(
+    low: Quantity<Q, F>,
+    high: Quantity<Q, F>,
+) -> QuantityRange<Q, F> {
+    QuantityRange::new(low, high)
+}
+
+impl<Q: QVal, F: PrefixFamily> QuantityRange<Q, F> {
+    /// Create a new range.
+    pub fn new(low: Quantity<Q, F>, high: Quantity<Q, F>) -> Self {
+        QuantityRange {
+            low,
+            high,
+            sep: "–",
+        }
+    }
+
+    /// Set the separator between the endpoints (an en dash by default).
+    pub fn separator(self, sep: &'static str) -> Self {
+        QuantityRange { sep, ..self }
+    }
+
+    /// Get the scale shared by both endpoints.
+    fn shared_scale(&self) -> Scale<F> {
+        let larger = if self.low.as_float().abs() > self.high.as_float().abs() {
+            &self.low
+        } else {
+            &self.high
+        };
+        match larger.resolve() {
+            Some((_v, _prec, pfx)) => Scale::Fixed(pfx),
+            None => Scale::Native,
+        }
+    }
+}
+
+impl<Q: QVal, F: PrefixFamily> fmt::Display for QuantityRange<Q, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scale = self.shared_scale();
+        let mut low = StackBuf::new();
+        self.low.write_value(&mut low, &scale)?;
+        let mut buf = StackBuf::new();
+        let form = self.high.write_value(&mut buf, &scale)?;
+        if low.as_str() != buf.as_str() {
+            let mut range = StackBuf::new();
+            fmt::Write::write_str(&mut range, low.as_str())?;
+            fmt::Write::write_str(&mut range, self.sep)?;
+            fmt::Write::write_str(&mut range, buf.as_str())?;
+            buf = range;
+        }
+        self.high.write_unit(&mut buf, &form)?;
+        if self.high.is_ascii() {
+            let mut ascii = StackBuf::new();
+            fmt::Write::write_str(&mut AsciiWriter::new(&mut ascii), buf.as_str())?;
+            buf = ascii;
+        }
+        buf.pad(f)
+    }
+}
+
+#[test]
+fn test_shared_prefix() {
+    use crate::scalar;
+    let r = range(scalar(850.0).suffix("W"), scalar(1500.0).suffix("W"));
+    assert_eq!(r.to_string().as_str(), "0.8500–1.500 kW");
+}
+
+#[test]
+fn test_separator() {
+    use crate::integer;
+    let r = range(integer(5), integer(10)).separator(" to ");
+    assert_eq!(r.to_string().as_str(), "5 to 10");
+}
+
+#[test]
+fn test_ascii() {
+    use crate::scalar;
+    let lo = scalar(1.2e-6).suffix("s").sig_figs(2);
+    let hi = scalar(3.4e-6).suffix("s").sig_figs(2);
+    let r = range(lo.clone().ascii(true), hi.clone().ascii(true));
+    assert_eq!(r.to_string().as_str(), "1.2-3.4 us");
+    assert_eq!(range(lo, hi).to_string().as_str(), "1.2–3.4 μs");
+}
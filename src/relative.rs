@@ -0,0 +1,326 @@
+//! Relative (“time ago”) display of durations.
+//!
+//! ```
+//! # use std::time::Duration;
+//! # use friendly::relative::{ago, until};
+//! assert_eq!(ago(Duration::from_secs(200)).to_string().as_str(), "3 minutes ago");
+//! assert_eq!(until(Duration::from_secs(7300)).to_string().as_str(), "in 2 hours");
+//! let r = ago(Duration::from_secs(200)).granularity(2).compact(true);
+//! assert_eq!(r.to_string().as_str(), "3m20s ago");
+//! ```
+//!
+//! Relative phrases are currently English only; the [language packs](crate::lang)
+//! apply to [HumanDuration] but not to this module.
+use std::fmt;
+use std::time::SystemTime;
+
+use crate::temporal::{
+    HumanDuration, Timestamp, DAY_NANOS, HOUR_NANOS, MIN_NANOS, MONTH_NANOS, SEC_NANOS, WEEK_NANOS,
+    YEAR_NANOS,
+};
+
+/// Units for relative phrasing, largest first.  Months and years are the same
+/// averages [HumanDuration] uses.
+const UNITS: &[(i128, &str, &str, &str)] = &[
+    (YEAR_NANOS, "y", "year", "years"),
+    (MONTH_NANOS, "mo", "month", "months"),
+    (WEEK_NANOS, "w", "week", "weeks"),
+    (DAY_NANOS, "d", "day", "days"),
+    (HOUR_NANOS, "h", "hour", "hours"),
+    (MIN_NANOS, "m", "minute", "minutes"),
+    (SEC_NANOS, "s", "second", "seconds"),
+];
+
+/// A duration displayed relative to now, in the past (“3 minutes ago”) or the future
+/// (“in 2 hours”).
+///
+/// Only the largest unit is displayed by default; [Relative::granularity] displays
+/// more.  Durations under a second are displayed as “just now”.
+#[derive(Debug, Clone, Copy)]
+pub struct Relative {
+    /// Signed nanoseconds; positive values are in the past.
+    nanos: i128,
+    parts: usize,
+    compact: bool,
+}
+
+/// Display a duration as a time in the past.
+pub fn ago<D: Into<HumanDuration>>(dur: D) -> Relative {
+    Relative::new(dur.into().as_nanos())
+}
+
+/// Display a duration as a time in the future.
+pub fn until<D: Into<HumanDuration>>(dur: D) -> Relative {
+    Relative::new(-dur.into().as_nanos())
+}
+
+/// Display the time elapsed since a point in time, relative to the current time.
+///
+/// This accepts any [Timestamp], including [SystemTime] and (with the `chrono`
+/// feature) Chrono's date-time types.  Times in the future are displayed as such
+/// (“in 5 minutes”), so clock skew or scheduled times do not need special handling.
+/// To fall back to absolute dates for distant times, use [crate::temporal::timestamp].
+pub fn since<T: Timestamp>(t: T) -> Relative {
+    since_at(t, SystemTime::now())
+}
+
+/// Compute the relative time of `t` with respect to `now`.
+fn since_at<T: Timestamp>(t: T, now: SystemTime) -> Relative {
+    Relative::new(now.unix_nanos() - t.unix_nanos())
+}
+
+impl Relative {
+    /// Create a relative time from signed nanoseconds (positive is in the past).
+    pub(crate) fn new(nanos: i128) -> Relative {
+        Relative {
+            nanos,
+            parts: 1,
+            compact: false,
+        }
+    }
+
+    /// Set the number of units to display (e.g. 2 for “3 minutes 20 seconds ago”).
+    pub fn granularity(self, parts: usize) -> Relative {
+        Relative {
+            parts: parts.max(1),
+            ..self
+        }
+    }
+
+    /// Set whether to use compact unit labels (“3m ago”).
+    pub fn compact(self, compact: bool) -> Relative {
+        Relative { compact, ..self }
+    }
+
+    /// Query whether this time is in the past.
+    pub fn is_past(&self) -> bool {
+        self.nanos > 0
+    }
+
+    fn write_units(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut rem = self.nanos.abs();
+        let mut written = 0;
+        for (unit, short, single, plural) in UNITS {
+            if written >= self.parts {
+                break;
+            }
+            let n = rem / unit;
+            rem %= unit;
+            if n == 0 {
+                continue;
+            }
+            if self.compact {
+                write!(f, "{}{}", n, short)?;
+            } else {
+                if written > 0 {
+                    f.write_str(" ")?;
+                }
+                let name = if n == 1 { single } else { plural };
+                write!(f, "{} {}", n, name)?;
+            }
+            written += 1;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Relative {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.nanos.abs() < SEC_NANOS {
+            f.write_str("just now")
+        } else if self.is_past() {
+            self.write_units(f)?;
+            f.write_str(" ago")
+        } else {
+            f.write_str("in ")?;
+            self.write_units(f)
+        }
+    }
+}
+
+/// Weekday names, starting from Monday.
+const WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// A timestamp described by its calendar day relative to the current day
+/// (“yesterday”, “last Tuesday”, “next week”).
+///
+/// Days more than two weeks away fall back to relative phrasing in whole days:
+///
+/// ```
+/// # use std::time::{Duration, UNIX_EPOCH};
+/// # use friendly::relative::calendar;
+/// let now = UNIX_EPOCH + Duration::from_secs(1_709_899_200); // Fri 2024-03-08 12:00
+/// let day = Duration::from_secs(86400);
+/// assert_eq!(calendar(now - day).relative_to(now).to_string().as_str(), "yesterday");
+/// assert_eq!(calendar(now - day * 3).relative_to(now).to_string().as_str(), "last Tuesday");
+/// assert_eq!(calendar(now + day * 9).relative_to(now).to_string().as_str(), "next week");
+/// assert_eq!(calendar(now - day * 21).relative_to(now).to_string().as_str(), "3 weeks ago");
+/// ```
+///
+/// Days are determined in the timestamp's own UTC offset.
+#[derive(Debug, Clone, Copy)]
+pub struct CalendarRelative {
+    nanos: i128,
+    offset: i32,
+    now: i128,
+}
+
+/// Describe a timestamp by its calendar day, relative to the current day.
+pub fn calendar<T: Timestamp>(t: T) -> CalendarRelative {
+    CalendarRelative {
+        nanos: t.unix_nanos(),
+        offset: t.utc_offset(),
+        now: SystemTime::now().unix_nanos(),
+    }
+}
+
+impl CalendarRelative {
+    /// Describe relative to a specific time instead of the current time.
+    pub fn relative_to<T: Timestamp>(self, now: T) -> CalendarRelative {
+        CalendarRelative {
+            now: now.unix_nanos(),
+            ..self
+        }
+    }
+
+    /// Get the calendar day (days since the epoch) of a time in this offset.
+    fn day(&self, nanos: i128) -> i128 {
+        (nanos + self.offset as i128 * SEC_NANOS).div_euclid(DAY_NANOS)
+    }
+}
+
+impl fmt::Display for CalendarRelative {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let day = self.day(self.nanos);
+        let diff = day - self.day(self.now);
+        // the epoch was a Thursday
+        let weekday = WEEKDAYS[(day + 3).rem_euclid(7) as usize];
+        match diff {
+            0 => f.write_str("today"),
+            -1 => f.write_str("yesterday"),
+            1 => f.write_str("tomorrow"),
+            -6..=-2 => write!(f, "last {}", weekday),
+            2..=6 => write!(f, "next {}", weekday),
+            -13..=-7 => f.write_str("last week"),
+            7..=13 => f.write_str("next week"),
+            _ => write!(f, "{}", Relative::new(-diff * DAY_NANOS)),
+        }
+    }
+}
+
+#[cfg(test)]
+use std::time::Duration;
+
+#[test]
+fn test_just_now() {
+    assert_eq!(
+        ago(Duration::from_millis(300)).to_string().as_str(),
+        "just now"
+    );
+}
+
+#[test]
+fn test_singular() {
+    assert_eq!(
+        ago(Duration::from_secs(61)).to_string().as_str(),
+        "1 minute ago"
+    );
+}
+
+#[test]
+fn test_granularity() {
+    let r = until(Duration::from_secs(2 * 86400 + 3 * 3600 + 5)).granularity(3);
+    assert_eq!(r.to_string().as_str(), "in 2 days 3 hours 5 seconds");
+}
+
+#[test]
+fn test_compact() {
+    let r = until(Duration::from_secs(90 * 60))
+        .compact(true)
+        .granularity(2);
+    assert_eq!(r.to_string().as_str(), "in 1h30m");
+}
+
+#[test]
+fn test_negative_ago() {
+    let r = ago(HumanDuration::new_from_secs(-120.0));
+    assert!(!r.is_past());
+    assert_eq!(r.to_string().as_str(), "in 2 minutes");
+}
+
+#[test]
+fn test_since() {
+    let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_709_856_000);
+    let r = since_at(now - Duration::from_secs(3 * 86400), now);
+    assert_eq!(r.to_string().as_str(), "3 days ago");
+    let r = since_at(now + Duration::from_secs(300), now);
+    assert_eq!(r.to_string().as_str(), "in 5 minutes");
+}
+
+#[test]
+fn test_since_now() {
+    let r = since(SystemTime::now());
+    assert_eq!(r.to_string().as_str(), "just now");
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_since_chrono() {
+    let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_709_856_000);
+    let t = chrono::DateTime::from_timestamp(1_709_856_000 - 7200, 0).unwrap();
+    assert_eq!(since_at(t, now).to_string().as_str(), "2 hours ago");
+    assert_eq!(
+        since_at(t.naive_utc(), now).to_string().as_str(),
+        "2 hours ago"
+    );
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_time_crate() {
+    let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_709_856_000);
+    let t = time::OffsetDateTime::from_unix_timestamp(1_709_856_000 - 300).unwrap();
+    assert_eq!(since_at(t, now).to_string().as_str(), "5 minutes ago");
+    let r = until(time::Duration::hours(3));
+    assert_eq!(r.to_string().as_str(), "in 3 hours");
+}
+
+#[test]
+fn test_calendar_day_boundary() {
+    // 23:30 and 00:30 the next day are an hour apart, but on different days
+    let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_709_857_800);
+    let t = now - Duration::from_secs(3600);
+    assert_eq!(
+        calendar(t).relative_to(now).to_string().as_str(),
+        "yesterday"
+    );
+    assert_eq!(calendar(now).relative_to(now).to_string().as_str(), "today");
+}
+
+#[test]
+fn test_calendar_future() {
+    let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_709_899_200);
+    let t = now + Duration::from_secs(3 * 86400);
+    assert_eq!(
+        calendar(t).relative_to(now).to_string().as_str(),
+        "next Monday"
+    );
+    let t = now + Duration::from_secs(30 * 86400);
+    assert_eq!(
+        calendar(t).relative_to(now).to_string().as_str(),
+        "in 4 weeks"
+    );
+    let t = now + Duration::from_secs(31 * 86400);
+    assert_eq!(
+        calendar(t).relative_to(now).to_string().as_str(),
+        "in 1 month"
+    );
+}
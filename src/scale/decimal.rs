@@ -13,6 +13,8 @@ impl Decimal {
         Decimal { pfx, exp }
     }
 
+    pub const QUECTO: Decimal = Decimal::new("q", -30);
+    pub const RONTO: Decimal = Decimal::new("r", -27);
     pub const YOCTO: Decimal = Decimal::new("y", -24);
     pub const ZEPTO: Decimal = Decimal::new("z", -21);
     pub const ATTO: Decimal = Decimal::new("a", -18);
@@ -21,7 +23,11 @@ impl Decimal {
     pub const NANO: Decimal = Decimal::new("n", -9);
     pub const MICRO: Decimal = Decimal::new("μ", -6);
     pub const MILLI: Decimal = Decimal::new("m", -3);
+    pub const CENTI: Decimal = Decimal::new("c", -2);
+    pub const DECI: Decimal = Decimal::new("d", -1);
     pub const UNIT: Decimal = Decimal::new("", 0);
+    pub const DECA: Decimal = Decimal::new("da", 1);
+    pub const HECTO: Decimal = Decimal::new("h", 2);
     pub const KILO: Decimal = Decimal::new("k", 3);
     pub const MEGA: Decimal = Decimal::new("M", 6);
     pub const GIGA: Decimal = Decimal::new("G", 9);
@@ -30,10 +36,14 @@ impl Decimal {
     pub const EXA: Decimal = Decimal::new("E", 18);
     pub const ZETTA: Decimal = Decimal::new("Z", 21);
     pub const YOTTA: Decimal = Decimal::new("Y", 24);
+    pub const RONNA: Decimal = Decimal::new("R", 27);
+    pub const QUETTA: Decimal = Decimal::new("Q", 30);
 
     pub const AUTO: Scale<Decimal> = Scale::Auto;
 
     pub const ALL_PREFIXES: &'static [&'static Decimal] = &[
+        &Decimal::QUECTO,
+        &Decimal::RONTO,
         &Decimal::YOCTO,
         &Decimal::ZEPTO,
         &Decimal::ATTO,
@@ -51,6 +61,37 @@ impl Decimal {
         &Decimal::EXA,
         &Decimal::ZETTA,
         &Decimal::YOTTA,
+        &Decimal::RONNA,
+        &Decimal::QUETTA,
+    ];
+
+    /// All prefixes, including the minor prefixes centi, deci, deca, and hecto.
+    pub const ALL_WITH_MINOR: &'static [&'static Decimal] = &[
+        &Decimal::QUECTO,
+        &Decimal::RONTO,
+        &Decimal::YOCTO,
+        &Decimal::ZEPTO,
+        &Decimal::ATTO,
+        &Decimal::FEMTO,
+        &Decimal::PICO,
+        &Decimal::NANO,
+        &Decimal::MICRO,
+        &Decimal::MILLI,
+        &Decimal::CENTI,
+        &Decimal::DECI,
+        &Decimal::UNIT,
+        &Decimal::DECA,
+        &Decimal::HECTO,
+        &Decimal::KILO,
+        &Decimal::MEGA,
+        &Decimal::GIGA,
+        &Decimal::TERA,
+        &Decimal::PETA,
+        &Decimal::EXA,
+        &Decimal::ZETTA,
+        &Decimal::YOTTA,
+        &Decimal::RONNA,
+        &Decimal::QUETTA,
     ];
 }
 
@@ -80,6 +121,10 @@ impl PrefixFamily for Decimal {
     fn all_prefixes() -> &'static [&'static Decimal] {
         Decimal::ALL_PREFIXES
     }
+
+    fn all_prefixes_with_minor() -> &'static [&'static Decimal] {
+        Decimal::ALL_WITH_MINOR
+    }
 }
 
 #[test]
@@ -99,3 +144,16 @@ fn test_unit_adjust() {
 fn test_kilo_adjust() {
     assert_eq!(Decimal::KILO.scale_value(1250), 1.250);
 }
+
+#[test]
+fn test_ronna_quetta() {
+    assert_eq!(Decimal::autoscale(5.2e28), (52.0, Decimal::RONNA));
+    assert_eq!(Decimal::autoscale(3e-29).1, Decimal::QUECTO);
+}
+
+#[test]
+fn test_minor_excluded() {
+    assert_eq!(Decimal::autoscale(0.05), (50.0, Decimal::MILLI));
+    let pfxs = Decimal::all_prefixes_with_minor();
+    assert_eq!(Decimal::autoscale_among(0.05, pfxs), (5.0, Decimal::CENTI));
+}
@@ -0,0 +1,88 @@
+use super::{Prefix, PrefixFamily, Scale};
+
+/// A scale for the Indian numbering system, with lakh (10⁵) and crore (10⁷).
+///
+/// ```
+/// # use friendly::scalar;
+/// # use friendly::scale::Indian;
+/// let q = scalar(12_500_000).scale(Indian::AUTO).sig_figs(3);
+/// assert_eq!(q.to_string().as_str(), "1.25 crore");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Indian {
+    word: &'static str,
+    exp: i32,
+}
+
+impl Indian {
+    const fn new(word: &'static str, exp: i32) -> Indian {
+        Indian { word, exp }
+    }
+
+    pub const UNIT: Indian = Indian::new("", 0);
+    pub const THOUSAND: Indian = Indian::new("thousand", 3);
+    pub const LAKH: Indian = Indian::new("lakh", 5);
+    pub const CRORE: Indian = Indian::new("crore", 7);
+    pub const ARAB: Indian = Indian::new("arab", 9);
+    pub const KHARAB: Indian = Indian::new("kharab", 11);
+
+    pub const AUTO: Scale<Indian> = Scale::Auto;
+
+    pub const ALL_PREFIXES: &'static [&'static Indian] = &[
+        &Indian::UNIT,
+        &Indian::THOUSAND,
+        &Indian::LAKH,
+        &Indian::CRORE,
+        &Indian::ARAB,
+        &Indian::KHARAB,
+    ];
+}
+
+impl Prefix for Indian {
+    #[inline]
+    fn base(&self) -> i32 {
+        10
+    }
+
+    #[inline]
+    fn exponent(&self) -> i32 {
+        self.exp
+    }
+
+    fn label(&self) -> &'static str {
+        self.word
+    }
+
+    fn is_word(&self) -> bool {
+        true
+    }
+}
+
+impl PrefixFamily for Indian {
+    type Prefix = Indian;
+
+    fn unit_prefix() -> Indian {
+        Indian::UNIT
+    }
+
+    fn all_prefixes() -> &'static [&'static Indian] {
+        Indian::ALL_PREFIXES
+    }
+}
+
+impl From<Indian> for Scale<Indian> {
+    fn from(p: Indian) -> Scale<Indian> {
+        Scale::Fixed(p)
+    }
+}
+
+#[test]
+fn test_lakh() {
+    assert_eq!(Indian::autoscale(250_000.0), (2.5, Indian::LAKH));
+    assert_eq!(Indian::autoscale(99_999.0), (99.999, Indian::THOUSAND));
+}
+
+#[test]
+fn test_crore() {
+    assert_eq!(Indian::autoscale(12_500_000.0), (1.25, Indian::CRORE));
+}
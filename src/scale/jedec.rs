@@ -0,0 +1,154 @@
+use super::{Binary, Prefix, PrefixFamily, Scale};
+
+/// A binary scale with JEDEC-style labels (“KB”, “MB”, “GB” meaning powers of 1024).
+///
+/// This is the same scale as [Binary], but labeled the way many tools and operating
+/// systems report memory and file sizes.
+///
+/// ```
+/// # use friendly::bytes;
+/// assert_eq!(bytes(13200).jedec().to_string().as_str(), "12.89 KB");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Jedec {
+    pfx: &'static str,
+    exp: i32,
+}
+
+impl Jedec {
+    const fn new(pfx: &'static str, exp: i32) -> Jedec {
+        Jedec { pfx, exp }
+    }
+
+    pub const UNIT: Jedec = Jedec::new("", 0);
+    pub const KILO: Jedec = Jedec::new("K", 10);
+    pub const MEGA: Jedec = Jedec::new("M", 20);
+    pub const GIGA: Jedec = Jedec::new("G", 30);
+    pub const TERA: Jedec = Jedec::new("T", 40);
+    pub const PETA: Jedec = Jedec::new("P", 50);
+    pub const EXA: Jedec = Jedec::new("E", 60);
+    pub const ZETTA: Jedec = Jedec::new("Z", 70);
+    pub const YOTTA: Jedec = Jedec::new("Y", 80);
+
+    pub const AUTO: Scale<Jedec> = Scale::Auto;
+
+    pub const ALL_PREFIXES: &'static [&'static Jedec] = &[
+        &Jedec::UNIT,
+        &Jedec::KILO,
+        &Jedec::MEGA,
+        &Jedec::GIGA,
+        &Jedec::TERA,
+        &Jedec::PETA,
+        &Jedec::EXA,
+        &Jedec::ZETTA,
+        &Jedec::YOTTA,
+    ];
+}
+
+/// The number of JEDEC prefixes.
+const COUNT: usize = Jedec::ALL_PREFIXES.len();
+
+/// Every subset of the JEDEC prefixes (with its length), indexed by a bit mask of
+/// positions in [Jedec::ALL_PREFIXES], so prefix lists can be relabeled as `'static`
+/// slices.
+static SUBSETS: [([&Jedec; COUNT], usize); 1 << COUNT] = subsets();
+
+const fn subsets() -> [([&'static Jedec; COUNT], usize); 1 << COUNT] {
+    let mut out = [([&Jedec::UNIT; COUNT], 0); 1 << COUNT];
+    let mut mask = 0;
+    while mask < out.len() {
+        let mut i = 0;
+        while i < COUNT {
+            if mask & (1 << i) != 0 {
+                let len = out[mask].1;
+                out[mask].0[len] = Jedec::ALL_PREFIXES[i];
+                out[mask].1 = len + 1;
+            }
+            i += 1;
+        }
+        mask += 1;
+    }
+    out
+}
+
+impl Jedec {
+    /// Get the JEDEC-labeled prefixes for a list of binary prefixes, in order of
+    /// increasing size.
+    pub(crate) fn relabel(pfxs: &[&Binary]) -> &'static [&'static Jedec] {
+        let mask = pfxs
+            .iter()
+            .fold(0, |mask, p| mask | 1 << (p.exponent() / 10));
+        let (list, len) = &SUBSETS[mask];
+        &list[..*len]
+    }
+}
+
+impl Prefix for Jedec {
+    #[inline]
+    fn base(&self) -> i32 {
+        2
+    }
+
+    #[inline]
+    fn exponent(&self) -> i32 {
+        self.exp
+    }
+
+    fn multiplier(&self) -> f64 {
+        let mult = 1u128 << self.exp;
+        mult as f64
+    }
+
+    fn label(&self) -> &'static str {
+        self.pfx
+    }
+}
+
+impl PrefixFamily for Jedec {
+    type Prefix = Jedec;
+
+    fn unit_prefix() -> Jedec {
+        Jedec::UNIT
+    }
+
+    fn all_prefixes() -> &'static [&'static Jedec] {
+        Jedec::ALL_PREFIXES
+    }
+}
+
+impl From<Jedec> for Scale<Jedec> {
+    fn from(p: Jedec) -> Scale<Jedec> {
+        Scale::Fixed(p)
+    }
+}
+
+impl From<Binary> for Jedec {
+    /// Get the JEDEC-labeled prefix for the same power of two.
+    fn from(b: Binary) -> Jedec {
+        Jedec::ALL_PREFIXES
+            .iter()
+            .find(|p| p.exp == b.exponent())
+            .map(|p| **p)
+            .expect("every binary prefix has a JEDEC label")
+    }
+}
+
+#[test]
+fn test_multipliers() {
+    assert_eq!(Jedec::KILO.multiplier(), Binary::KIBI.multiplier());
+    assert_eq!(Jedec::GIGA.multiplier(), Binary::GIBI.multiplier());
+}
+
+#[test]
+fn test_from_binary() {
+    assert_eq!(Jedec::from(Binary::MEBI), Jedec::MEGA);
+    assert_eq!(Jedec::from(Binary::UNIT), Jedec::UNIT);
+}
+
+#[test]
+fn test_relabel() {
+    let pfxs = Jedec::relabel(&[&Binary::KIBI, &Binary::GIBI]);
+    assert_eq!(pfxs, &[&Jedec::KILO, &Jedec::GIGA]);
+    assert_eq!(Jedec::relabel(Binary::ALL_PREFIXES), Jedec::ALL_PREFIXES);
+    assert!(Jedec::relabel(&[]).is_empty());
+}
@@ -3,11 +3,21 @@ use std::fmt::Debug;
 
 mod binary;
 mod decimal;
+mod indian;
+mod jedec;
+mod myriad;
+mod stable;
 #[cfg(test)]
 pub(crate) mod test;
+mod words;
 
 pub use binary::Binary;
 pub use decimal::Decimal;
+pub use indian::Indian;
+pub use jedec::Jedec;
+pub use myriad::Myriad;
+pub use stable::StableScaler;
+pub use words::{LongWords, Words};
 
 /// Trait for an individual prefix.
 pub trait Prefix: Debug + Clone + Copy + PartialEq {
@@ -32,6 +42,12 @@ pub trait Prefix: Debug + Clone + Copy + PartialEq {
 
     /// Get the label for this prefix.
     fn label(&self) -> &'static str;
+
+    /// Whether this prefix's label is a word, which is set apart from a following
+    /// unit with a space (“3.2 million B”) rather than joined to it.
+    fn is_word(&self) -> bool {
+        false
+    }
 }
 
 /// Trait for a collection of (related) prefixes.
@@ -43,18 +59,29 @@ pub trait PrefixFamily {
     /// Get all prefixes for this scheme.  The prefixes must be in sorted order.
     fn all_prefixes() -> &'static [&'static Self::Prefix];
 
+    /// Get all prefixes for this scheme, including minor prefixes (such as centi
+    /// and hecto) that are normally excluded from auto-scaling.  The prefixes must be
+    /// in sorted order.
+    fn all_prefixes_with_minor() -> &'static [&'static Self::Prefix] {
+        Self::all_prefixes()
+    }
+
     /// Auto-scale a value.
     fn autoscale(val: f64) -> (f64, Self::Prefix) {
-        if !val.is_finite() || !val.is_normal() {
+        Self::autoscale_among(val, Self::all_prefixes())
+    }
+
+    /// Auto-scale a value to the best-fitting prefix in a list (in sorted order).
+    fn autoscale_among(val: f64, pfxs: &[&Self::Prefix]) -> (f64, Self::Prefix) {
+        if !val.is_finite() || !val.is_normal() || pfxs.is_empty() {
             // non-finite values just get displayed, as does ~0
             return (val, Self::unit_prefix());
         }
 
-        let pfxs = Self::all_prefixes();
         let mut iter = pfxs.iter();
         // always have at least one
         let mut cur = iter.next().unwrap();
-        while let Some(next) = iter.next() {
+        for next in iter {
             // check fit w.r.t. next
             if next.scale_value(val).abs() < 1.0 {
                 // next is too small, 'cur' is what we want
@@ -67,19 +94,119 @@ pub trait PrefixFamily {
 
         (cur.scale_value(val), **cur)
     }
+
+    /// Choose a single prefix for a whole set of values, so they display comparably.
+    ///
+    /// This picks the prefix that best fits the value with the largest magnitude;
+    /// non-finite values are ignored.
+    ///
+    /// ```
+    /// # use friendly::scale::{Binary, PrefixFamily};
+    /// let pfx = Binary::autoscale_all(&[419_430.0, 851_443_712.0]);
+    /// assert_eq!(pfx, Binary::MEBI);
+    /// ```
+    fn autoscale_all(vals: &[f64]) -> Self::Prefix {
+        let max = vals
+            .iter()
+            .map(|v| v.abs())
+            .filter(|v| v.is_finite())
+            .fold(0.0, f64::max);
+        Self::autoscale(max).1
+    }
 }
 
 /// A scale
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum Scale<F: PrefixFamily> {
     /// Auto-scale to the best-fitting prefix.
     Auto,
+    /// Auto-scale to the best-fitting prefix between two bounds (inclusive),
+    /// saturating at the bounds.
+    AutoRange(F::Prefix, F::Prefix),
+    /// Auto-scale to the best-fitting prefix in a list, which must be in sorted
+    /// order.
+    ///
+    /// ```
+    /// # use friendly::scalar;
+    /// # use friendly::scale::{Decimal, Scale};
+    /// const COMMON: &[&Decimal] = &[&Decimal::UNIT, &Decimal::KILO, &Decimal::MEGA];
+    /// let q = scalar(3.2e13).scale(Scale::<Decimal>::AutoWithin(COMMON));
+    /// assert_eq!(q.to_string().as_str(), "32000000 M");
+    /// let q = scalar(3.2e-4).scale(Scale::<Decimal>::AutoWithin(COMMON));
+    /// assert_eq!(q.to_string().as_str(), "0.0003200");
+    /// ```
+    AutoWithin(&'static [&'static F::Prefix]),
     /// Display the number in its native scale.
     Native,
     /// Use a specific fixed prefix.
     Fixed(F::Prefix),
 }
 
+// prefixes are always `Copy`, so scales are too, whatever the family type
+impl<F: PrefixFamily> Clone for Scale<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: PrefixFamily> Copy for Scale<F> {}
+
+/// How to write prefix labels.
+///
+/// Strict SI and IEC labels are the default; the informal styles are for interfaces
+/// whose users expect labels like “12.9 KB”.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PrefixStyle {
+    /// Standard labels (“k”, “Ki”).
+    #[default]
+    Strict,
+    /// An uppercase “K” for kilo, with other labels unchanged.
+    UpperKilo,
+    /// An uppercase “K” for kilo, and binary prefixes without the “i” (“KB”, “MB”).
+    Informal,
+}
+
+impl PrefixStyle {
+    /// Get the label to write for a prefix in this style.
+    ///
+    /// ```
+    /// # use friendly::scale::{Binary, Decimal, PrefixStyle};
+    /// assert_eq!(PrefixStyle::UpperKilo.label(&Decimal::KILO), "K");
+    /// assert_eq!(PrefixStyle::UpperKilo.label(&Binary::MEBI), "Mi");
+    /// assert_eq!(PrefixStyle::Informal.label(&Binary::MEBI), "M");
+    /// ```
+    pub fn label<P: Prefix>(&self, pfx: &P) -> &'static str {
+        let label = pfx.label();
+        if *self == PrefixStyle::Strict || pfx.is_word() {
+            return label;
+        }
+        match label {
+            "k" => "K",
+            l if *self == PrefixStyle::Informal && pfx.base() == 2 => {
+                l.strip_suffix('i').unwrap_or(l)
+            }
+            l => l,
+        }
+    }
+}
+
+/// Select the prefixes from a sorted list whose exponents lie between two bounds.
+pub(crate) fn prefix_range<'a, P: Prefix>(pfxs: &'a [&'a P], lo: &P, hi: &P) -> &'a [&'a P] {
+    let start = pfxs
+        .iter()
+        .position(|p| p.exponent() >= lo.exponent())
+        .unwrap_or(pfxs.len());
+    let end = pfxs
+        .iter()
+        .rposition(|p| p.exponent() <= hi.exponent())
+        .map_or(0, |i| i + 1);
+    if start < end {
+        &pfxs[start..end]
+    } else {
+        &[]
+    }
+}
+
 impl From<Decimal> for Scale<Decimal> {
     fn from(p: Decimal) -> Scale<Decimal> {
         Scale::Fixed(p)
@@ -91,3 +218,20 @@ impl From<Binary> for Scale<Binary> {
         Scale::Fixed(p)
     }
 }
+
+#[test]
+fn test_prefix_range() {
+    let pfxs = prefix_range(Binary::ALL_PREFIXES, &Binary::UNIT, &Binary::GIBI);
+    assert_eq!(pfxs.len(), 4);
+    assert_eq!(Binary::autoscale_among(5e12, pfxs).1, Binary::GIBI);
+    assert!(prefix_range(Binary::ALL_PREFIXES, &Binary::GIBI, &Binary::KIBI).is_empty());
+}
+
+#[test]
+fn test_prefix_style_unchanged() {
+    for style in [PrefixStyle::UpperKilo, PrefixStyle::Informal] {
+        assert_eq!(style.label(&Decimal::MILLI), "m");
+        assert_eq!(style.label(&Decimal::MEGA), "M");
+        assert_eq!(style.label(&Words::THOUSAND), "thousand");
+    }
+}
@@ -0,0 +1,87 @@
+use super::{Prefix, PrefixFamily, Scale};
+
+/// A myriad-based scale, as used in Japanese and Chinese (万, 億, 兆, 京).
+///
+/// Each step of this scale is a factor of 10⁴ rather than 10³, so values are scaled
+/// to the range [1, 10000).
+///
+/// ```
+/// # use friendly::scalar;
+/// # use friendly::scale::Myriad;
+/// let q = scalar(125_000_000).scale(Myriad::AUTO).space(false);
+/// assert_eq!(q.to_string().as_str(), "1.250億");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Myriad {
+    pfx: &'static str,
+    exp: i32,
+}
+
+impl Myriad {
+    const fn new(pfx: &'static str, exp: i32) -> Myriad {
+        Myriad { pfx, exp }
+    }
+
+    pub const UNIT: Myriad = Myriad::new("", 0);
+    pub const MAN: Myriad = Myriad::new("万", 1);
+    pub const OKU: Myriad = Myriad::new("億", 2);
+    pub const CHO: Myriad = Myriad::new("兆", 3);
+    pub const KEI: Myriad = Myriad::new("京", 4);
+
+    pub const AUTO: Scale<Myriad> = Scale::Auto;
+
+    pub const ALL_PREFIXES: &'static [&'static Myriad] = &[
+        &Myriad::UNIT,
+        &Myriad::MAN,
+        &Myriad::OKU,
+        &Myriad::CHO,
+        &Myriad::KEI,
+    ];
+}
+
+impl Prefix for Myriad {
+    #[inline]
+    fn base(&self) -> i32 {
+        10_000
+    }
+
+    #[inline]
+    fn exponent(&self) -> i32 {
+        self.exp
+    }
+
+    fn label(&self) -> &'static str {
+        self.pfx
+    }
+}
+
+impl PrefixFamily for Myriad {
+    type Prefix = Myriad;
+
+    fn unit_prefix() -> Myriad {
+        Myriad::UNIT
+    }
+
+    fn all_prefixes() -> &'static [&'static Myriad] {
+        Myriad::ALL_PREFIXES
+    }
+}
+
+impl From<Myriad> for Scale<Myriad> {
+    fn from(p: Myriad) -> Scale<Myriad> {
+        Scale::Fixed(p)
+    }
+}
+
+#[test]
+fn test_multipliers() {
+    assert_eq!(Myriad::MAN.multiplier(), 1e4);
+    assert_eq!(Myriad::OKU.multiplier(), 1e8);
+    assert_eq!(Myriad::CHO.multiplier(), 1e12);
+}
+
+#[test]
+fn test_man() {
+    assert_eq!(Myriad::autoscale(35_000.0), (3.5, Myriad::MAN));
+    assert_eq!(Myriad::autoscale(9_999.0), (9_999.0, Myriad::UNIT));
+}
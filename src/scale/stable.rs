@@ -0,0 +1,126 @@
+//! Stable auto-scaling for values that are displayed repeatedly.
+use super::{Prefix, PrefixFamily, Scale};
+
+/// An auto-scaler that remembers its last prefix, for live-updating displays.
+///
+/// Plain auto-scaling picks a new prefix as soon as a value crosses a boundary, so a
+/// value hovering near it flickers between (e.g.) “999.8 KiB” and “1.000 MiB”.  This
+/// scaler only switches prefixes once the value is well past the boundary (by 10%
+/// by default):
+///
+/// ```
+/// # use friendly::bytes;
+/// # use friendly::scale::{Binary, StableScaler};
+/// let mut scaler = StableScaler::<Binary>::new();
+/// let q = bytes(1000 * 1024).scale(scaler.scale(1000.0 * 1024.0));
+/// assert_eq!(q.to_string().as_str(), "1000 KiB");
+/// let q = bytes(1030 * 1024).scale(scaler.scale(1030.0 * 1024.0));
+/// assert_eq!(q.to_string().as_str(), "1030 KiB");
+/// let q = bytes(1200 * 1024).scale(scaler.scale(1200.0 * 1024.0));
+/// assert_eq!(q.to_string().as_str(), "1.172 MiB");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct StableScaler<F: PrefixFamily> {
+    current: Option<F::Prefix>,
+    margin: f64,
+}
+
+impl<F: PrefixFamily> Default for StableScaler<F> {
+    fn default() -> Self {
+        StableScaler {
+            current: None,
+            margin: 0.1,
+        }
+    }
+}
+
+impl<F: PrefixFamily> StableScaler<F> {
+    /// Create a new stable scaler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set how far past a boundary (as a fraction) a value must move before the
+    /// prefix changes.
+    pub fn margin(self, margin: f64) -> Self {
+        StableScaler { margin, ..self }
+    }
+
+    /// Get the current prefix, if a value has been scaled.
+    pub fn prefix(&self) -> Option<F::Prefix> {
+        self.current
+    }
+
+    /// Forget the current prefix.
+    pub fn reset(&mut self) {
+        self.current = None;
+    }
+
+    /// Scale a value, updating the current prefix if needed.
+    pub fn rescale(&mut self, val: f64) -> (f64, F::Prefix) {
+        let (_, best) = F::autoscale(val);
+        let pfx = match self.current {
+            Some(cur) if val.is_normal() => {
+                let mag = val.abs();
+                if best.exponent() > cur.exponent() {
+                    // growing: switch once well above the new prefix's boundary
+                    if mag >= best.multiplier() * (1.0 + self.margin) {
+                        best
+                    } else {
+                        cur
+                    }
+                } else if best.exponent() < cur.exponent() {
+                    // shrinking: switch once well below the current prefix's boundary
+                    if mag < cur.multiplier() * (1.0 - self.margin) {
+                        best
+                    } else {
+                        cur
+                    }
+                } else {
+                    cur
+                }
+            }
+            Some(cur) => cur,
+            None => best,
+        };
+        self.current = Some(pfx);
+        (pfx.scale_value(val), pfx)
+    }
+
+    /// Scale a value, returning a fixed [Scale] for use with [crate::Quantity::scale].
+    pub fn scale(&mut self, val: f64) -> Scale<F> {
+        let (_, pfx) = self.rescale(val);
+        Scale::Fixed(pfx)
+    }
+}
+
+#[cfg(test)]
+use super::Binary;
+
+#[test]
+fn test_first_value() {
+    let mut sc = StableScaler::<Binary>::new();
+    assert_eq!(sc.prefix(), None);
+    assert_eq!(sc.rescale(2048.0), (2.0, Binary::KIBI));
+    assert_eq!(sc.prefix(), Some(Binary::KIBI));
+}
+
+#[test]
+fn test_no_flicker() {
+    let mut sc = StableScaler::<Binary>::new();
+    sc.rescale(1023.0 * 1024.0);
+    assert_eq!(sc.rescale(1025.0 * 1024.0).1, Binary::KIBI);
+    assert_eq!(sc.rescale(1023.0 * 1024.0).1, Binary::KIBI);
+    assert_eq!(sc.rescale(2.0 * 1024.0 * 1024.0).1, Binary::MEBI);
+    assert_eq!(sc.rescale(1000.0 * 1024.0).1, Binary::MEBI);
+    assert_eq!(sc.rescale(900.0 * 1024.0).1, Binary::KIBI);
+}
+
+#[test]
+fn test_big_jump() {
+    let mut sc = StableScaler::<Binary>::new();
+    sc.rescale(10.0);
+    assert_eq!(sc.rescale(5e9).1, Binary::GIBI);
+    sc.reset();
+    assert_eq!(sc.rescale(10.0).1, Binary::UNIT);
+}
@@ -4,6 +4,7 @@ use proptest::sample::*;
 
 use super::binary::Binary;
 use super::decimal::Decimal;
+use super::myriad::Myriad;
 
 use super::*;
 
@@ -25,12 +26,37 @@ proptest! {
   #[test]
   fn test_dec_autoscale(f in any::<f64>()) {
     let (sx, scale) = Decimal::autoscale(f);
-    if scale.exponent() > -24 && f.is_normal() {
+    if scale.exponent() > -30 && f.is_normal() {
       assert!(sx.abs() >= 1.0);
     }
-    if scale.exponent() < 24 {
+    if scale.exponent() < 30 {
       assert!(sx.abs() < 1000.0);
     }
     assert_relative_eq!(sx * scale.multiplier(), f);
   }
+
+  #[test]
+  fn test_myriad_autoscale(f in any::<f64>()) {
+    let (sx, scale) = Myriad::autoscale(f);
+    if scale.exponent() > 0 && f.is_normal() {
+      assert!(sx.abs() >= 1.0);
+    }
+    if scale.exponent() < 4 {
+      assert!(sx.abs() < 10_000.0);
+    }
+    assert_relative_eq!(sx * scale.multiplier(), f);
+  }
+  #[test]
+  fn test_dec_autoscale_all(vals in proptest::collection::vec(-1e20..1e20f64, 1..10)) {
+    let scale = Decimal::autoscale_all(&vals);
+    for v in vals {
+      assert!(scale.scale_value(v).abs() < 1000.0);
+    }
+  }
+}
+
+#[test]
+fn test_autoscale_all_empty() {
+    assert_eq!(Binary::autoscale_all(&[]), Binary::UNIT);
+    assert_eq!(Binary::autoscale_all(&[f64::NAN, 2048.0]), Binary::KIBI);
 }
@@ -0,0 +1,169 @@
+use super::{Prefix, PrefixFamily, Scale};
+
+/// A scale of number words on the short scale (“thousand”, “million”, “billion”).
+///
+/// ```
+/// # use friendly::quantity::Quantity;
+/// # use friendly::scale::Words;
+/// let q = Quantity::decimal(3_200_000).scale(Words::AUTO).sig_figs(2);
+/// assert_eq!(q.to_string().as_str(), "3.2 million");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Words {
+    word: &'static str,
+    exp: i32,
+}
+
+impl Words {
+    const fn new(word: &'static str, exp: i32) -> Words {
+        Words { word, exp }
+    }
+
+    pub const UNIT: Words = Words::new("", 0);
+    pub const THOUSAND: Words = Words::new("thousand", 3);
+    pub const MILLION: Words = Words::new("million", 6);
+    pub const BILLION: Words = Words::new("billion", 9);
+    pub const TRILLION: Words = Words::new("trillion", 12);
+    pub const QUADRILLION: Words = Words::new("quadrillion", 15);
+    pub const QUINTILLION: Words = Words::new("quintillion", 18);
+
+    pub const AUTO: Scale<Words> = Scale::Auto;
+
+    pub const ALL_PREFIXES: &'static [&'static Words] = &[
+        &Words::UNIT,
+        &Words::THOUSAND,
+        &Words::MILLION,
+        &Words::BILLION,
+        &Words::TRILLION,
+        &Words::QUADRILLION,
+        &Words::QUINTILLION,
+    ];
+}
+
+impl Prefix for Words {
+    #[inline]
+    fn base(&self) -> i32 {
+        10
+    }
+
+    #[inline]
+    fn exponent(&self) -> i32 {
+        self.exp
+    }
+
+    fn label(&self) -> &'static str {
+        self.word
+    }
+
+    fn is_word(&self) -> bool {
+        true
+    }
+}
+
+impl PrefixFamily for Words {
+    type Prefix = Words;
+
+    fn unit_prefix() -> Words {
+        Words::UNIT
+    }
+
+    fn all_prefixes() -> &'static [&'static Words] {
+        Words::ALL_PREFIXES
+    }
+}
+
+impl From<Words> for Scale<Words> {
+    fn from(p: Words) -> Scale<Words> {
+        Scale::Fixed(p)
+    }
+}
+
+/// A scale of number words on the long scale (“million”, “milliard”, “billion”),
+/// as traditionally used in much of continental Europe.
+///
+/// ```
+/// # use friendly::quantity::Quantity;
+/// # use friendly::scale::LongWords;
+/// let q = Quantity::decimal(3_200_000_000u64).scale(LongWords::AUTO).sig_figs(2);
+/// assert_eq!(q.to_string().as_str(), "3.2 milliard");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LongWords {
+    word: &'static str,
+    exp: i32,
+}
+
+impl LongWords {
+    const fn new(word: &'static str, exp: i32) -> LongWords {
+        LongWords { word, exp }
+    }
+
+    pub const UNIT: LongWords = LongWords::new("", 0);
+    pub const THOUSAND: LongWords = LongWords::new("thousand", 3);
+    pub const MILLION: LongWords = LongWords::new("million", 6);
+    pub const MILLIARD: LongWords = LongWords::new("milliard", 9);
+    pub const BILLION: LongWords = LongWords::new("billion", 12);
+    pub const BILLIARD: LongWords = LongWords::new("billiard", 15);
+    pub const TRILLION: LongWords = LongWords::new("trillion", 18);
+
+    pub const AUTO: Scale<LongWords> = Scale::Auto;
+
+    pub const ALL_PREFIXES: &'static [&'static LongWords] = &[
+        &LongWords::UNIT,
+        &LongWords::THOUSAND,
+        &LongWords::MILLION,
+        &LongWords::MILLIARD,
+        &LongWords::BILLION,
+        &LongWords::BILLIARD,
+        &LongWords::TRILLION,
+    ];
+}
+
+impl Prefix for LongWords {
+    #[inline]
+    fn base(&self) -> i32 {
+        10
+    }
+
+    #[inline]
+    fn exponent(&self) -> i32 {
+        self.exp
+    }
+
+    fn label(&self) -> &'static str {
+        self.word
+    }
+
+    fn is_word(&self) -> bool {
+        true
+    }
+}
+
+impl PrefixFamily for LongWords {
+    type Prefix = LongWords;
+
+    fn unit_prefix() -> LongWords {
+        LongWords::UNIT
+    }
+
+    fn all_prefixes() -> &'static [&'static LongWords] {
+        LongWords::ALL_PREFIXES
+    }
+}
+
+impl From<LongWords> for Scale<LongWords> {
+    fn from(p: LongWords) -> Scale<LongWords> {
+        Scale::Fixed(p)
+    }
+}
+
+#[test]
+fn test_short_scale() {
+    assert_eq!(Words::autoscale(2.5e9), (2.5, Words::BILLION));
+    assert_eq!(Words::autoscale(950.0), (950.0, Words::UNIT));
+}
+
+#[test]
+fn test_long_scale() {
+    assert_eq!(LongWords::autoscale(2.5e12), (2.5, LongWords::BILLION));
+}
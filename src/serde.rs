@@ -0,0 +1,191 @@
+//! Serde helpers for human-readable fields (with the `serde` feature).
+//!
+//! These modules are used with `#[serde(with = "...")]` to serialize numeric fields
+//! as human-readable strings, and to parse them back (with [crate::parse]):
+//!
+//! ```
+//! # use std::time::Duration;
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Status {
+//!     #[serde(with = "friendly::serde::bytes")]
+//!     size: u64,
+//!     #[serde(with = "friendly::serde::duration")]
+//!     elapsed: Duration,
+//! }
+//!
+//! let st = Status { size: 13200, elapsed: Duration::from_secs(90) };
+//! let json = serde_json::to_string(&st).unwrap();
+//! assert_eq!(json.as_str(), r#"{"size":"12.89 KiB","elapsed":"1m30.00s"}"#);
+//!
+//! let st: Status = serde_json::from_str(r#"{"size":"512 MiB","elapsed":"90s"}"#).unwrap();
+//! assert_eq!(st.size, 512 << 20);
+//! assert_eq!(st.elapsed, Duration::from_secs(90));
+//! ```
+//!
+//! The strings are approximate, like the rest of this crate's display, so values do
+//! not always round-trip exactly.  Deserialization also accepts plain numbers (bytes,
+//! seconds, or the scalar value).
+use std::fmt;
+use std::marker::PhantomData;
+
+use ::serde::de::{self, Visitor};
+
+use crate::parse::ParseError;
+
+/// A visitor that parses human-readable strings or accepts plain numbers.
+struct Human<T> {
+    expecting: &'static str,
+    parse: fn(&str) -> Result<T, ParseError>,
+    from_u64: fn(u64) -> Option<T>,
+    from_f64: fn(f64) -> Option<T>,
+    _t: PhantomData<T>,
+}
+
+impl<'de, T> Visitor<'de> for Human<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        (self.parse)(v).map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        (self.from_u64)(v).ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+        if v >= 0 {
+            self.visit_u64(v as u64)
+        } else {
+            (self.from_f64)(v as f64)
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Signed(v), &self))
+        }
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<T, E> {
+        (self.from_f64)(v).ok_or_else(|| E::invalid_value(de::Unexpected::Float(v), &self))
+    }
+}
+
+/// Serialize a byte count as a string like “12.89 KiB” (see [crate::bytes]), and
+/// deserialize with [parse_bytes](crate::parse::parse_bytes).
+pub mod bytes {
+    use std::marker::PhantomData;
+
+    use ::serde::{Deserializer, Serializer};
+
+    use super::Human;
+    use crate::parse::parse_bytes;
+    use crate::quantity::QVal;
+
+    /// Serialize a byte count.
+    pub fn serialize<V, S>(val: &V, ser: S) -> Result<S::Ok, S::Error>
+    where
+        V: QVal + Copy,
+        S: Serializer,
+    {
+        ser.collect_str(&crate::bytes(*val))
+    }
+
+    /// Deserialize a byte count.
+    pub fn deserialize<'de, D: Deserializer<'de>>(de: D) -> Result<u64, D::Error> {
+        de.deserialize_any(Human {
+            expecting: "a size in bytes",
+            parse: parse_bytes,
+            from_u64: Some,
+            from_f64: |v| (v >= 0.0 && v < u64::MAX as f64).then(|| v.round() as u64),
+            _t: PhantomData,
+        })
+    }
+}
+
+/// Serialize a [Duration](std::time::Duration) as a string like “1m30.00s” (see
+/// [crate::duration]), and deserialize with
+/// [parse_duration](crate::parse::parse_duration).
+pub mod duration {
+    use std::marker::PhantomData;
+    use std::time::Duration;
+
+    use ::serde::{Deserializer, Serializer};
+
+    use super::Human;
+    use crate::parse::parse_duration;
+
+    /// Serialize a duration.
+    pub fn serialize<S: Serializer>(val: &Duration, ser: S) -> Result<S::Ok, S::Error> {
+        ser.collect_str(&crate::duration(*val))
+    }
+
+    /// Deserialize a duration.
+    pub fn deserialize<'de, D: Deserializer<'de>>(de: D) -> Result<Duration, D::Error> {
+        de.deserialize_any(Human {
+            expecting: "a duration",
+            parse: parse_duration,
+            from_u64: |v| Some(Duration::from_secs(v)),
+            from_f64: |v| Duration::try_from_secs_f64(v).ok(),
+            _t: PhantomData,
+        })
+    }
+}
+
+/// Serialize a number as a string with an SI prefix, like “23.48 m” (see
+/// [crate::scalar]), and deserialize with [parse_scalar](crate::parse::parse_scalar).
+pub mod scalar {
+    use std::marker::PhantomData;
+
+    use ::serde::{Deserializer, Serializer};
+
+    use super::Human;
+    use crate::parse::parse_scalar;
+    use crate::quantity::QVal;
+
+    /// Serialize a number.
+    pub fn serialize<V, S>(val: &V, ser: S) -> Result<S::Ok, S::Error>
+    where
+        V: QVal + Copy,
+        S: Serializer,
+    {
+        ser.collect_str(&crate::scalar(*val))
+    }
+
+    /// Deserialize a number.
+    pub fn deserialize<'de, D: Deserializer<'de>>(de: D) -> Result<f64, D::Error> {
+        de.deserialize_any(Human {
+            expecting: "a number",
+            parse: parse_scalar,
+            from_u64: |v| Some(v as f64),
+            from_f64: Some,
+            _t: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+#[derive(Debug, ::serde::Serialize, ::serde::Deserialize)]
+struct Rate {
+    #[serde(with = "crate::serde::scalar")]
+    rate: f64,
+}
+
+#[test]
+fn test_scalar_field() {
+    let json = serde_json::to_string(&Rate { rate: 0.023477 }).unwrap();
+    assert_eq!(json.as_str(), r#"{"rate":"23.48 m"}"#);
+    let rate: Rate = serde_json::from_str(&json).unwrap();
+    assert_eq!(rate.rate, 0.02348);
+}
+
+#[test]
+fn test_plain_numbers() {
+    let rate: Rate = serde_json::from_str(r#"{"rate": 42}"#).unwrap();
+    assert_eq!(rate.rate, 42.0);
+}
+
+#[test]
+fn test_parse_error() {
+    let err = serde_json::from_str::<Rate>(r#"{"rate": "3 x"}"#).unwrap_err();
+    assert!(err.to_string().contains("unknown unit"), "{}", err);
+}
@@ -1,28 +1,120 @@
 //! Routines for significant figures
 use std::cmp::max;
 
+/// Rounding modes for displayed values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rounding {
+    /// Round to nearest, with halves away from zero (the default).
+    #[default]
+    HalfUp,
+    /// Round to nearest, with halves to the even digit (“banker's rounding”).
+    HalfEven,
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceil,
+    /// Round toward zero.
+    Truncate,
+}
+
+impl Rounding {
+    /// Round a value to an integer with this mode.
+    pub fn round(&self, val: f64) -> f64 {
+        match self {
+            Rounding::HalfUp => val.round(),
+            Rounding::HalfEven => val.round_ties_even(),
+            Rounding::Floor => val.floor(),
+            Rounding::Ceil => val.ceil(),
+            Rounding::Truncate => val.trunc(),
+        }
+    }
+}
+
+/// Divide two integers, rounding the quotient with a mode.  `neg` is the sign of
+/// the quotient, for directed rounding.
+pub(crate) fn div_round(num: u128, den: u128, neg: bool, mode: Rounding) -> u128 {
+    let (q, r) = (num / den, num % den);
+    // compare the remainder to half the divisor without overflowing
+    let up = match mode {
+        Rounding::HalfUp => r > 0 && r >= den - r,
+        Rounding::HalfEven => r > den - r || (r > 0 && r == den - r && q % 2 == 1),
+        Rounding::Floor => neg && r > 0,
+        Rounding::Ceil => !neg && r > 0,
+        Rounding::Truncate => false,
+    };
+    if up {
+        q + 1
+    } else {
+        q
+    }
+}
+
 /// Adjust a value for the specified number of significant figures.
 ///
 /// Returns a tuple of the adjusted value (rounded as appropriate) and the number of
 /// values after the decimal point needed to display it.
 pub fn sigscale(val: f64, sf: usize) -> (f64, usize) {
+    sigscale_with(val, sf, Rounding::HalfUp)
+}
+
+/// Adjust a value for the specified number of significant figures, with a
+/// rounding mode.
+pub fn sigscale_with(val: f64, sf: usize, mode: Rounding) -> (f64, usize) {
     if val.is_normal() {
         let sf = sf as i32;
         let log = val.abs().log10();
         let mut scale = log.ceil() as i32;
         if log == log.ceil() {
-            scale = scale + 1; // we're exactly at the boundary
+            scale += 1; // we're exactly at the boundary
         }
         // how much do we need to shift befeore rounding?
         let scale_diff = sf - scale;
         let adj = 10.0f64.powi(scale_diff);
-        let adj_val = (val * adj).round() / adj;
+        let adj_val = mode.round(val * adj) / adj;
         (adj_val, max(scale_diff, 0) as usize)
     } else {
         (val, sf)
     }
 }
 
+/// Round a value to a fixed number of decimal places.
+///
+/// Returns the same kind of tuple as [sigscale], so the two can be used
+/// interchangeably.
+pub fn decscale(val: f64, decimals: usize) -> (f64, usize) {
+    decscale_with(val, decimals, Rounding::HalfUp)
+}
+
+/// Round a value to a fixed number of decimal places, with a rounding mode.
+pub fn decscale_with(val: f64, decimals: usize, mode: Rounding) -> (f64, usize) {
+    if val.is_finite() {
+        let adj = 10.0f64.powi(decimals as i32);
+        (mode.round(val * adj) / adj, decimals)
+    } else {
+        (val, decimals)
+    }
+}
+
+/// Round a value and its uncertainty by the usual convention: the uncertainty is
+/// rounded to 1 significant figure (2 if its leading digit is 1), and the value is
+/// rounded to the same decimal place.
+///
+/// Returns the rounded value, the rounded uncertainty, and the number of digits
+/// after the decimal point needed to display them.
+pub fn errscale(val: f64, err: f64) -> (f64, f64, usize) {
+    let err = err.abs();
+    if !err.is_normal() {
+        return (val, err, 0);
+    }
+    let lead = err.log10().floor() as i32;
+    let digits = if err / 10.0f64.powi(lead) < 2.0 { 2 } else { 1 };
+    let place = lead - (digits - 1);
+    let adj = 10.0f64.powi(-place);
+    let val = (val * adj).round() / adj;
+    let err = (err * adj).round() / adj;
+    (val, err, max(-place, 0) as usize)
+}
+
 #[test]
 fn test_zero() {
     let (v, prec) = sigscale(0.0, 4);
@@ -50,3 +142,37 @@ fn test_small() {
     assert_eq!(v, 0.00032);
     assert_eq!(prec, 5);
 }
+
+#[test]
+fn test_decscale() {
+    assert_eq!(decscale(0.5, 2), (0.5, 2));
+    assert_eq!(decscale(123.456, 1), (123.5, 1));
+    assert_eq!(decscale(1234.5, 0), (1235.0, 0));
+}
+
+#[test]
+fn test_rounding_modes() {
+    assert_eq!(sigscale_with(2.5, 1, Rounding::HalfUp), (3.0, 0));
+    assert_eq!(sigscale_with(2.5, 1, Rounding::HalfEven), (2.0, 0));
+    assert_eq!(sigscale_with(-2.45, 2, Rounding::Floor), (-2.5, 1));
+    assert_eq!(sigscale_with(2.41, 2, Rounding::Ceil), (2.5, 1));
+    assert_eq!(sigscale_with(-2.49, 2, Rounding::Truncate), (-2.4, 1));
+    assert_eq!(decscale_with(1.239, 2, Rounding::Truncate), (1.23, 2));
+}
+
+#[test]
+fn test_errscale() {
+    assert_eq!(errscale(12.34, 0.43), (12.3, 0.4, 1));
+    assert_eq!(errscale(12.345, 0.14), (12.35, 0.14, 2));
+    assert_eq!(errscale(1234.0, 43.0), (1230.0, 40.0, 0));
+}
+
+#[test]
+fn test_div_round() {
+    assert_eq!(div_round(25, 10, false, Rounding::HalfUp), 3);
+    assert_eq!(div_round(25, 10, false, Rounding::HalfEven), 2);
+    assert_eq!(div_round(35, 10, false, Rounding::HalfEven), 4);
+    assert_eq!(div_round(21, 10, true, Rounding::Floor), 3);
+    assert_eq!(div_round(21, 10, false, Rounding::Floor), 2);
+    assert_eq!(div_round(20, 10, false, Rounding::Ceil), 2);
+}
@@ -0,0 +1,45 @@
+//! Spacing between numbers and units.
+
+/// The space to write between a number and its unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpaceStyle {
+    /// No space (“12.89KiB”).
+    None,
+    /// An ordinary space (“12.89 KiB”).
+    #[default]
+    Space,
+    /// A non-breaking space (U+00A0), so the number and unit stay on one line.
+    Nbsp,
+    /// A narrow non-breaking space (U+202F), as recommended for SI typesetting.
+    NarrowNbsp,
+}
+
+impl SpaceStyle {
+    /// Get the text of this space, if any.
+    pub fn text(&self) -> Option<&'static str> {
+        match self {
+            SpaceStyle::None => None,
+            SpaceStyle::Space => Some(" "),
+            SpaceStyle::Nbsp => Some("\u{a0}"),
+            SpaceStyle::NarrowNbsp => Some("\u{202f}"),
+        }
+    }
+}
+
+/// `true` writes an ordinary space, and `false` writes no space.
+impl From<bool> for SpaceStyle {
+    fn from(space: bool) -> SpaceStyle {
+        if space {
+            SpaceStyle::Space
+        } else {
+            SpaceStyle::None
+        }
+    }
+}
+
+#[test]
+fn test_text() {
+    assert_eq!(SpaceStyle::from(true).text(), Some(" "));
+    assert_eq!(SpaceStyle::from(false).text(), None);
+    assert_eq!(SpaceStyle::NarrowNbsp.text(), Some("\u{202f}"));
+}
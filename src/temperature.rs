@@ -0,0 +1,146 @@
+//! Display of temperatures, with unit conversion.
+//!
+//! Temperatures are not scaled with prefixes; they are converted to the requested
+//! unit and written with a fixed number of decimal places:
+//!
+//! ```
+//! # use friendly::temperature::{celsius, Unit};
+//! assert_eq!(celsius(21.7).to_string().as_str(), "21.7 °C");
+//! assert_eq!(celsius(22.3).to(Unit::F).to_string().as_str(), "72.1 °F");
+//! assert_eq!(celsius(26.85).to(Unit::K).to_string().as_str(), "300.0 K");
+//! ```
+use std::fmt;
+
+use crate::spacing::SpaceStyle;
+
+/// Temperature units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    /// Degrees Celsius.
+    C,
+    /// Degrees Fahrenheit.
+    F,
+    /// Kelvin.
+    K,
+}
+
+impl Unit {
+    /// Get the symbol for this unit (with the degree sign, if it uses one).
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Unit::C => "°C",
+            Unit::F => "°F",
+            Unit::K => "K",
+        }
+    }
+
+    /// Convert a temperature in this unit to kelvin.
+    fn to_kelvin(self, val: f64) -> f64 {
+        match self {
+            Unit::C => val + 273.15,
+            Unit::F => (val - 32.0) * 5.0 / 9.0 + 273.15,
+            Unit::K => val,
+        }
+    }
+
+    /// Convert a temperature in kelvin to this unit.
+    fn of_kelvin(self, val: f64) -> f64 {
+        match self {
+            Unit::C => val - 273.15,
+            Unit::F => (val - 273.15) * 9.0 / 5.0 + 32.0,
+            Unit::K => val,
+        }
+    }
+}
+
+/// A temperature to display.
+#[derive(Debug, Clone, Copy)]
+pub struct Temperature {
+    value: f64,
+    unit: Unit,
+    display: Unit,
+    decimals: usize,
+    spc: SpaceStyle,
+}
+
+/// Display a temperature in degrees Celsius.
+pub fn celsius(value: f64) -> Temperature {
+    Temperature::new(value, Unit::C)
+}
+
+/// Display a temperature in degrees Fahrenheit.
+pub fn fahrenheit(value: f64) -> Temperature {
+    Temperature::new(value, Unit::F)
+}
+
+/// Display a temperature in kelvin.
+pub fn kelvin(value: f64) -> Temperature {
+    Temperature::new(value, Unit::K)
+}
+
+impl Temperature {
+    /// Create a new temperature in the specified unit.
+    pub fn new(value: f64, unit: Unit) -> Temperature {
+        Temperature {
+            value,
+            unit,
+            display: unit,
+            decimals: 1,
+            spc: SpaceStyle::Space,
+        }
+    }
+
+    /// Convert the temperature to another unit for display.
+    pub fn to(self, display: Unit) -> Temperature {
+        Temperature { display, ..self }
+    }
+
+    /// Set the number of decimal places.
+    pub fn decimals(self, decimals: usize) -> Temperature {
+        Temperature { decimals, ..self }
+    }
+
+    /// Change the space before the unit.
+    pub fn space<S: Into<SpaceStyle>>(self, space: S) -> Temperature {
+        Temperature {
+            spc: space.into(),
+            ..self
+        }
+    }
+
+    /// Get the temperature in its display unit.
+    pub fn value(&self) -> f64 {
+        if self.unit == self.display {
+            self.value
+        } else {
+            self.display.of_kelvin(self.unit.to_kelvin(self.value))
+        }
+    }
+}
+
+impl fmt::Display for Temperature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.*}", self.decimals, self.value())?;
+        if let Some(spc) = self.spc.text() {
+            f.write_str(spc)?;
+        }
+        f.write_str(self.display.symbol())
+    }
+}
+
+#[test]
+fn test_freezing() {
+    assert_eq!(fahrenheit(32.0).to(Unit::C).to_string().as_str(), "0.0 °C");
+    assert_eq!(kelvin(273.15).to(Unit::F).to_string().as_str(), "32.0 °F");
+}
+
+#[test]
+fn test_negative() {
+    assert_eq!(celsius(-40.0).to(Unit::F).to_string().as_str(), "-40.0 °F");
+}
+
+#[test]
+fn test_options() {
+    let t = celsius(21.66).decimals(0).space(false);
+    assert_eq!(t.to_string().as_str(), "22°C");
+}
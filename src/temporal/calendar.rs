@@ -0,0 +1,182 @@
+//! Civil (proleptic Gregorian) calendar computations.
+use super::DAY_NANOS;
+
+pub(crate) const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// A calendar date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct CivilDate {
+    pub year: i64,
+    /// Month (1–12).
+    pub month: u32,
+    /// Day of the month (1–31).
+    pub day: u32,
+}
+
+impl CivilDate {
+    /// Compute the date for a number of days since the Unix epoch.
+    ///
+    /// This uses Howard Hinnant's `civil_from_days` algorithm.
+    pub fn from_unix_days(days: i64) -> CivilDate {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+        CivilDate { year, month, day }
+    }
+
+    /// Compute the number of days since the Unix epoch for this date.
+    ///
+    /// This uses Howard Hinnant's `days_from_civil` algorithm.
+    pub fn to_unix_days(self) -> i64 {
+        let year = if self.month <= 2 {
+            self.year - 1
+        } else {
+            self.year
+        };
+        let era = year.div_euclid(400);
+        let yoe = year.rem_euclid(400);
+        let month = self.month as i64;
+        let mp = if month > 2 { month - 3 } else { month + 9 };
+        let doy = (153 * mp + 2) / 5 + self.day as i64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    /// Add a number of months to this date, clamping the day to the end of the month.
+    pub fn add_months(&self, months: i64) -> CivilDate {
+        let index = self.year * 12 + self.month as i64 - 1 + months;
+        let year = index.div_euclid(12);
+        let month = index.rem_euclid(12) as u32 + 1;
+        CivilDate {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    /// Get the full name of this date's month.
+    pub fn month_name(&self) -> &'static str {
+        MONTH_NAMES[self.month as usize - 1]
+    }
+}
+
+/// Get the number of days in a month.
+pub(crate) fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Largest number of days from the epoch for which the date arithmetic in
+/// [calendar_months] cannot overflow.
+const MAX_DAYS: i128 = (i64::MAX / 4) as i128;
+
+/// Split a (non-negative) span starting at a timestamp into whole calendar months and
+/// the remaining nanoseconds.
+///
+/// Returns `None` if the span ends too far from the epoch to compute calendar dates.
+pub(crate) fn calendar_months(start: i128, nanos: i128) -> Option<(i64, i128)> {
+    let end = start.checked_add(nanos)?;
+    let (start_days, end_days) = (start.div_euclid(DAY_NANOS), end.div_euclid(DAY_NANOS));
+    if start_days.abs() > MAX_DAYS || end_days.abs() > MAX_DAYS {
+        return None;
+    }
+    let tod = start.rem_euclid(DAY_NANOS);
+    let first = CivilDate::from_unix_days(start_days as i64);
+    let last = CivilDate::from_unix_days(end_days as i64);
+    let anchor = |months: i64| first.add_months(months).to_unix_days() as i128 * DAY_NANOS + tod;
+
+    let mut months = (last.year - first.year) * 12 + last.month as i64 - first.month as i64;
+    while months > 0 && anchor(months) > end {
+        months -= 1;
+    }
+    let months = months.max(0);
+    Some((months, end - anchor(months)))
+}
+
+#[test]
+fn test_epoch() {
+    let d = CivilDate::from_unix_days(0);
+    assert_eq!(
+        d,
+        CivilDate {
+            year: 1970,
+            month: 1,
+            day: 1
+        }
+    );
+}
+
+#[test]
+fn test_leap_day() {
+    // 2024-02-29
+    let d = CivilDate::from_unix_days(19_782);
+    assert_eq!((d.year, d.month, d.day), (2024, 2, 29));
+    assert_eq!(d.month_name(), "February");
+}
+
+#[test]
+fn test_before_epoch() {
+    let d = CivilDate::from_unix_days(-1);
+    assert_eq!((d.year, d.month, d.day), (1969, 12, 31));
+}
+
+#[test]
+fn test_round_trip_days() {
+    for days in [-800_000, -1, 0, 19_782, 2_932_896] {
+        assert_eq!(CivilDate::from_unix_days(days).to_unix_days(), days);
+    }
+}
+
+#[test]
+fn test_add_months_clamps() {
+    // 2024-01-31 + 1 month is 2024-02-29
+    let d = CivilDate::from_unix_days(19_753).add_months(1);
+    assert_eq!((d.year, d.month, d.day), (2024, 2, 29));
+    let d = d.add_months(-14);
+    assert_eq!((d.year, d.month, d.day), (2022, 12, 29));
+}
+
+#[test]
+fn test_calendar_months() {
+    // 2024-01-15 to 2024-03-20 is 2 months and 5 days
+    let start = 19_737 * DAY_NANOS;
+    let (months, rest) = calendar_months(start, 65 * DAY_NANOS).unwrap();
+    assert_eq!((months, rest), (2, 5 * DAY_NANOS));
+    assert_eq!(calendar_months(start, DAY_NANOS), Some((0, DAY_NANOS)));
+}
+
+#[test]
+fn test_calendar_months_overflow() {
+    let start = 19_737 * DAY_NANOS;
+    assert_eq!(calendar_months(start, i128::MAX), None);
+    assert_eq!(calendar_months(start, MAX_DAYS * DAY_NANOS), None);
+
+    // the display falls back to fixed-length months
+    use super::seconds;
+    use std::time::{Duration, UNIX_EPOCH};
+    let d = seconds(1e30).anchored(UNIX_EPOCH + Duration::from_secs(1_705_276_800));
+    assert_eq!(d.to_string(), seconds(1e30).years_months(true).to_string());
+}
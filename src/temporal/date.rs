@@ -0,0 +1,85 @@
+//! Display of calendar dates.
+use std::fmt;
+
+use super::calendar::{days_in_month, CivilDate};
+use super::timestamp::DateFormat;
+
+/// A calendar date to display.
+///
+/// Dates are displayed with full month names and ordinal days by default:
+///
+/// ```
+/// # use friendly::temporal::{DateFormat, HumanDate};
+/// let d = HumanDate::from_ymd(2024, 3, 3).unwrap();
+/// assert_eq!(d.to_string().as_str(), "March 3rd, 2024");
+/// let d = d.date_format(DateFormat::Short);
+/// assert_eq!(d.to_string().as_str(), "Mar 3rd, 2024");
+/// assert_eq!(d.ordinal(false).to_string().as_str(), "Mar 3, 2024");
+/// ```
+///
+/// With the `chrono` feature, `friendly::date` displays Chrono's date types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HumanDate {
+    date: CivilDate,
+    format: DateFormat,
+    ordinal: bool,
+}
+
+impl HumanDate {
+    /// Create a date from a year, month (1–12), and day of the month, if it is valid.
+    pub fn from_ymd(year: i64, month: u32, day: u32) -> Option<HumanDate> {
+        if (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month) {
+            Some(HumanDate {
+                date: CivilDate { year, month, day },
+                format: DateFormat::Long,
+                ordinal: true,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Set the date format.
+    pub fn date_format(self, format: DateFormat) -> HumanDate {
+        HumanDate { format, ..self }
+    }
+
+    /// Set whether to write the day as an ordinal (“3rd”) in formats with month
+    /// names.
+    pub fn ordinal(self, ordinal: bool) -> HumanDate {
+        HumanDate { ordinal, ..self }
+    }
+}
+
+/// Display a date (with the `chrono` feature).
+///
+/// ```
+/// # use friendly::date;
+/// let d = chrono::NaiveDate::from_ymd_opt(2024, 3, 22).unwrap();
+/// assert_eq!(date(d).to_string().as_str(), "March 22nd, 2024");
+/// ```
+#[cfg(feature = "chrono")]
+pub fn date<D: chrono::Datelike>(d: D) -> HumanDate {
+    HumanDate::from_ymd(d.year() as i64, d.month(), d.day()).expect("valid date")
+}
+
+impl fmt::Display for HumanDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format.write_date(f, self.date, self.ordinal)
+    }
+}
+
+#[test]
+fn test_invalid() {
+    assert_eq!(HumanDate::from_ymd(2023, 2, 29), None);
+    assert_eq!(HumanDate::from_ymd(2024, 13, 1), None);
+    assert!(HumanDate::from_ymd(2024, 2, 29).is_some());
+}
+
+#[test]
+fn test_iso_ignores_ordinal() {
+    let d = HumanDate::from_ymd(2024, 3, 11).unwrap();
+    assert_eq!(d.to_string().as_str(), "March 11th, 2024");
+    let d = d.date_format(DateFormat::Iso);
+    assert_eq!(d.to_string().as_str(), "2024-03-11");
+}
@@ -0,0 +1,1229 @@
+//! Human-readable time features.
+#[cfg(feature = "chrono")]
+use chrono;
+use std::cmp::Ordering;
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::ascii::AsciiWriter;
+use crate::buffer::StackBuf;
+use crate::grouping::write_number;
+#[cfg(test)]
+use crate::lang::Lang;
+use crate::lang::{Language, Operands, TimeUnit};
+use crate::numfmt::{trimmed_precision, write_fixed};
+use crate::scalar;
+use crate::sigfig::sigscale;
+use calendar::calendar_months;
+use style::{write_clock, write_days_clock, write_fuzzy, write_iso8601, ClockOpts};
+
+mod calendar;
+mod date;
+mod recurrence;
+mod style;
+mod timestamp;
+
+#[cfg(feature = "chrono")]
+pub use date::date;
+pub use date::HumanDate;
+pub use recurrence::{every, Recurrence};
+pub use style::{Fuzzy, Negative, Style, UnitRounding};
+pub use timestamp::{timestamp, DateFormat, HumanTimestamp};
+
+const MIN_SECS: f64 = 60.0;
+const HOUR_SECS: f64 = MIN_SECS * 60.0;
+
+pub(crate) const SEC_NANOS: i128 = 1_000_000_000;
+pub(crate) const MIN_NANOS: i128 = SEC_NANOS * 60;
+pub(crate) const HOUR_NANOS: i128 = MIN_NANOS * 60;
+pub(crate) const DAY_NANOS: i128 = HOUR_NANOS * 24;
+pub(crate) const WEEK_NANOS: i128 = DAY_NANOS * 7;
+/// An average month (1/12 of a 365.25-day year).
+pub(crate) const MONTH_NANOS: i128 = DAY_NANOS * 365 / 12 + HOUR_NANOS / 2;
+/// An average (365.25-day) year.
+pub(crate) const YEAR_NANOS: i128 = 12 * MONTH_NANOS;
+
+/// Duration parts, with their lengths in nanoseconds and compact labels.
+const PART_UNITS: &[(i128, &str, TimeUnit)] = &[
+    (YEAR_NANOS, "y", TimeUnit::Year),
+    (MONTH_NANOS, "mo", TimeUnit::Month),
+    (WEEK_NANOS, "w", TimeUnit::Week),
+    (DAY_NANOS, "d", TimeUnit::Day),
+    (HOUR_NANOS, "h", TimeUnit::Hour),
+    (MIN_NANOS, "m", TimeUnit::Minute),
+    (SEC_NANOS, "s", TimeUnit::Second),
+    (1_000_000, "ms", TimeUnit::Millisecond),
+    (1_000, "μs", TimeUnit::Microsecond),
+    (1, "ns", TimeUnit::Nanosecond),
+];
+
+/// Human-displayable durations (from [Duration]).
+///
+/// There are several settings to tweak on a displayable duration:
+///
+/// - Whether it is full (“3 hours 2 minutes 3.42 seconds”) or compact (“2h2m3.42s”)
+/// - How many components are displayed (e.g. with 3 parts, “5d3h2m” will omit seconds)
+/// - The [Style] to display it in (units, or clock-style “05:32:10”)
+///
+/// The default is compact display with 3 parts.
+///
+/// Durations are stored as integer nanoseconds, so they are exact for any
+/// [Duration] (or a signed duration up to ±5×10²¹ years).  Durations created from
+/// non-finite seconds display as “∞”, “-∞”, or “NaN”; finite ones past that range are
+/// clamped to it (see [HumanDuration::is_saturated]).
+pub struct HumanDuration {
+    nanos: i128,
+    /// The original seconds, if they were not finite or were clamped to fit.
+    float: Option<f64>,
+    compact: bool,
+    parts: i32,
+    point: char,
+    /// Significant figures for sub-minute durations.
+    nsig: u32,
+    lang: &'static dyn Language,
+    style: Style,
+    clock: ClockOpts,
+    subsec: bool,
+    negative: Negative,
+    months: Option<MonthSpan>,
+    fuzzy: Fuzzy,
+    rounding: UnitRounding,
+    max_unit: TimeUnit,
+    min_unit: TimeUnit,
+    ascii: bool,
+}
+
+/// How to compute year and month parts.
+#[derive(Debug, Clone, Copy)]
+enum MonthSpan {
+    /// Fixed-length years (365.25 days) and months (1/12 year).
+    Approximate,
+    /// Calendar years and months from a starting timestamp (in local nanoseconds).
+    Calendar(i128),
+}
+
+impl From<Duration> for HumanDuration {
+    fn from(d: Duration) -> HumanDuration {
+        HumanDuration::new_from_nanos(d.as_nanos() as i128)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::Duration> for HumanDuration {
+    fn from(d: chrono::Duration) -> HumanDuration {
+        let nanos = d
+            .num_nanoseconds()
+            .map(|n| n as i128)
+            .unwrap_or_else(|| d.num_milliseconds() as i128 * 1_000_000);
+        HumanDuration::new_from_nanos(nanos)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Duration> for HumanDuration {
+    fn from(d: time::Duration) -> HumanDuration {
+        HumanDuration::new_from_nanos(d.whole_nanoseconds())
+    }
+}
+
+impl HumanDuration {
+    /// Create a new readable duration from seconds.
+    ///
+    /// Durations longer than ±[i128::MAX] nanoseconds (about 5.4×10²¹ years) are
+    /// clamped to that length for display, but [as_secs_f64](HumanDuration::as_secs_f64)
+    /// still returns the original value.
+    ///
+    /// ```
+    /// # use friendly::seconds;
+    /// let d = seconds(1e30);
+    /// assert!(d.is_saturated());
+    /// assert_eq!(d.as_nanos(), i128::MAX);
+    /// assert_eq!(d.as_secs_f64(), 1e30);
+    /// ```
+    pub fn new_from_secs(seconds: f64) -> HumanDuration {
+        HumanDuration::new_from_float(seconds, 1e9)
+    }
+
+    /// Create a duration from a floating-point value in a unit (given in nanoseconds),
+    /// keeping values that do not fit in integer nanoseconds.
+    fn new_from_float(value: f64, unit: f64) -> HumanDuration {
+        let nanos = value * unit;
+        // i128::MAX rounds up to 2^127 as a float
+        if nanos.abs() < 2f64.powi(127) {
+            return HumanDuration::new_from_nanos(nanos.round() as i128);
+        }
+        let saturated = match nanos {
+            n if n.is_nan() => 0,
+            n if n > 0.0 => i128::MAX,
+            _ => -i128::MAX,
+        };
+        HumanDuration {
+            float: Some(value * (unit / 1e9)),
+            ..HumanDuration::new_from_nanos(saturated)
+        }
+    }
+
+    /// Create a new readable duration from (signed) nanoseconds.
+    pub fn new_from_nanos(nanos: i128) -> HumanDuration {
+        let cfg = crate::config::defaults();
+        HumanDuration {
+            nanos,
+            float: None,
+            compact: true,
+            parts: 3,
+            point: cfg.point,
+            nsig: cfg.nsig,
+            lang: cfg.lang.as_static(),
+            style: Style::Units,
+            clock: ClockOpts::default(),
+            subsec: false,
+            negative: Negative::Minus,
+            months: None,
+            fuzzy: Fuzzy::default(),
+            rounding: UnitRounding::Floor,
+            max_unit: TimeUnit::Year,
+            min_unit: TimeUnit::Nanosecond,
+            ascii: cfg.ascii,
+        }
+    }
+
+    /// Get the duration in nanoseconds.
+    pub fn as_nanos(&self) -> i128 {
+        self.nanos
+    }
+
+    /// Get the duration in (fractional) seconds.
+    pub fn as_secs_f64(&self) -> f64 {
+        self.float.unwrap_or(self.nanos as f64 * 1e-9)
+    }
+
+    /// Query whether this duration was too long (or not finite) to store exactly, so
+    /// its nanoseconds are clamped.
+    pub fn is_saturated(&self) -> bool {
+        self.float.is_some()
+    }
+
+    /// Set whether display is compact.
+    pub fn compact(self, compact: bool) -> HumanDuration {
+        HumanDuration { compact, ..self }
+    }
+
+    /// Set the number of parts to display (0 for all).
+    pub fn parts(self, parts: i32) -> HumanDuration {
+        HumanDuration { parts, ..self }
+    }
+
+    /// Set whether to break seconds into millisecond, microsecond, and nanosecond
+    /// parts instead of displaying fractional seconds.
+    ///
+    /// ```
+    /// # use friendly::seconds;
+    /// let d = seconds(123.421).subsecond_parts(true).parts(0);
+    /// assert_eq!(d.to_string().as_str(), "2m3s421ms");
+    /// ```
+    pub fn subsecond_parts(self, subsec: bool) -> HumanDuration {
+        HumanDuration { subsec, ..self }
+    }
+
+    /// Set whether to display year and month parts, using fixed-length years
+    /// (365.25 days) and months (1/12 year).
+    ///
+    /// ```
+    /// # use friendly::seconds;
+    /// let d = seconds(2.3 * 365.25 * 86400.0).years_months(true).parts(2);
+    /// assert_eq!(d.to_string().as_str(), "2y3mo");
+    /// ```
+    pub fn years_months(self, on: bool) -> HumanDuration {
+        let months = if on {
+            Some(MonthSpan::Approximate)
+        } else {
+            None
+        };
+        HumanDuration { months, ..self }
+    }
+
+    /// Display year and month parts computed on the calendar, for a span starting at
+    /// a given time.  Negative durations are treated as spans of the same length, and
+    /// spans too long for calendar dates fall back to fixed-length months.
+    ///
+    /// ```
+    /// # use std::time::{Duration, UNIX_EPOCH};
+    /// # use friendly::duration;
+    /// let start = UNIX_EPOCH + Duration::from_secs(1_705_276_800); // 2024-01-15
+    /// let d = duration(Duration::from_secs(65 * 86400)).anchored(start);
+    /// assert_eq!(d.to_string().as_str(), "2mo0w5d");
+    /// ```
+    pub fn anchored<T: Timestamp>(self, start: T) -> HumanDuration {
+        let local = start.unix_nanos() + start.utc_offset() as i128 * SEC_NANOS;
+        HumanDuration {
+            months: Some(MonthSpan::Calendar(local)),
+            ..self
+        }
+    }
+
+    /// Set how negative durations are displayed.
+    ///
+    /// ```
+    /// # use friendly::seconds;
+    /// # use friendly::temporal::Negative;
+    /// assert_eq!(seconds(-90.0).to_string().as_str(), "-1m30.00s");
+    /// let d = seconds(-90.0).negative(Negative::Ago);
+    /// assert_eq!(d.to_string().as_str(), "1m30.00s ago");
+    /// ```
+    pub fn negative(self, negative: Negative) -> HumanDuration {
+        HumanDuration { negative, ..self }
+    }
+
+    /// Set the display style.
+    pub fn style(self, style: Style) -> HumanDuration {
+        HumanDuration { style, ..self }
+    }
+
+    /// Display in clock style (“05:32:10”).
+    ///
+    /// ```
+    /// # use friendly::seconds;
+    /// let d = seconds(5.0 * 3600.0 + 32.0 * 60.0 + 10.5).clock();
+    /// assert_eq!(d.to_string().as_str(), "05:32:10");
+    /// let d = seconds(185.0).clock().pad(false).omit_hours(true);
+    /// assert_eq!(d.to_string().as_str(), "3:05");
+    /// ```
+    pub fn clock(self) -> HumanDuration {
+        self.style(Style::Clock)
+    }
+
+    /// Display as an ISO 8601 duration (“PT5H32M10.5S”).
+    ///
+    /// ```
+    /// # use friendly::seconds;
+    /// let d = seconds(5.0 * 3600.0 + 32.0 * 60.0 + 10.5).iso8601();
+    /// assert_eq!(d.to_string().as_str(), "PT5H32M10.5S");
+    /// ```
+    pub fn iso8601(self) -> HumanDuration {
+        self.style(Style::Iso8601)
+    }
+
+    /// Display as an approximate phrase (“about an hour”, “almost 2 days”).
+    ///
+    /// ```
+    /// # use friendly::seconds;
+    /// assert_eq!(seconds(3725.0).fuzzy().to_string().as_str(), "about an hour");
+    /// assert_eq!(seconds(1.8 * 86400.0).fuzzy().to_string().as_str(), "almost 2 days");
+    /// assert_eq!(seconds(4.0).fuzzy().to_string().as_str(), "a few seconds");
+    /// ```
+    pub fn fuzzy(self) -> HumanDuration {
+        self.style(Style::Fuzzy)
+    }
+
+    /// Display as an approximate phrase with custom thresholds.
+    pub fn fuzzy_thresholds(self, fuzzy: Fuzzy) -> HumanDuration {
+        HumanDuration {
+            fuzzy,
+            ..self.style(Style::Fuzzy)
+        }
+    }
+
+    /// Set whether to zero-pad the leading field in clock style (default `true`).
+    pub fn pad(self, pad: bool) -> HumanDuration {
+        let clock = ClockOpts { pad, ..self.clock };
+        HumanDuration { clock, ..self }
+    }
+
+    /// Set whether to omit the hours in clock style when they are zero (default
+    /// `false`).
+    pub fn omit_hours(self, omit: bool) -> HumanDuration {
+        let clock = ClockOpts {
+            hours: !omit,
+            ..self.clock
+        };
+        HumanDuration { clock, ..self }
+    }
+
+    /// Set the largest unit to display; larger parts are expressed in this unit.
+    ///
+    /// ```
+    /// # use friendly::seconds;
+    /// # use friendly::lang::TimeUnit;
+    /// let d = seconds(3.0 * 86400.0 + 125.0).max_unit(TimeUnit::Hour).min_unit(TimeUnit::Minute);
+    /// assert_eq!(d.to_string().as_str(), "72h2m");
+    /// ```
+    pub fn max_unit(self, max_unit: TimeUnit) -> HumanDuration {
+        HumanDuration { max_unit, ..self }
+    }
+
+    /// Set the smallest unit to display; smaller parts are omitted.
+    pub fn min_unit(self, min_unit: TimeUnit) -> HumanDuration {
+        HumanDuration { min_unit, ..self }
+    }
+
+    /// Set how the last displayed part is rounded when later parts are omitted.
+    ///
+    /// ```
+    /// # use friendly::seconds;
+    /// # use friendly::temporal::UnitRounding;
+    /// let d = seconds(5.0 * 3600.0 + 59.0 * 60.0 + 40.0).parts(2);
+    /// assert_eq!(d.to_string().as_str(), "5h59m");
+    /// let d = seconds(5.0 * 3600.0 + 59.0 * 60.0 + 40.0).parts(2);
+    /// assert_eq!(d.rounding(UnitRounding::Nearest).to_string().as_str(), "6h0m");
+    /// ```
+    pub fn rounding(self, rounding: UnitRounding) -> HumanDuration {
+        HumanDuration { rounding, ..self }
+    }
+
+    /// Set the decimal separator used for fractional seconds.
+    ///
+    /// ```
+    /// # use friendly::seconds;
+    /// let d = seconds(5.0 * 3600.0 + 32.0 * 60.0 + 10.5).decimal_separator(',');
+    /// assert_eq!(d.to_string().as_str(), "5h32m10,50s");
+    /// ```
+    pub fn decimal_separator(self, point: char) -> HumanDuration {
+        HumanDuration { point, ..self }
+    }
+
+    /// Set the language for unit words in non-compact display.
+    ///
+    /// ```
+    /// # use friendly::seconds;
+    /// # use friendly::lang::Lang;
+    /// let d = seconds(3725.0).compact(false).parts(2).lang(&Lang::Fr);
+    /// assert_eq!(d.to_string().as_str(), "1 heure 2 minutes");
+    /// ```
+    pub fn lang(self, lang: &'static dyn Language) -> HumanDuration {
+        HumanDuration { lang, ..self }
+    }
+
+    /// Set whether to write ASCII-only output (“250.0us” instead of “250.0μs”).
+    pub fn ascii(self, ascii: bool) -> HumanDuration {
+        HumanDuration { ascii, ..self }
+    }
+}
+
+/// Make a duration displayable.
+pub fn duration<D: Into<HumanDuration>>(dur: D) -> HumanDuration {
+    dur.into()
+}
+
+/// Create a duration from seconds (see [HumanDuration::new_from_secs] for very long
+/// durations).
+pub fn seconds(secs: f64) -> HumanDuration {
+    HumanDuration::new_from_secs(secs)
+}
+
+/// Create a duration from milliseconds, clamped like [seconds].
+///
+/// ```
+/// # use friendly::temporal::millis;
+/// assert_eq!(millis(324.0).to_string().as_str(), "324.0ms");
+/// ```
+pub fn millis(ms: f64) -> HumanDuration {
+    HumanDuration::new_from_float(ms, 1e6)
+}
+
+/// Create a duration from microseconds, clamped like [seconds].
+pub fn micros(us: f64) -> HumanDuration {
+    HumanDuration::new_from_float(us, 1e3)
+}
+
+/// Create a duration from nanoseconds.
+pub fn nanos(ns: i128) -> HumanDuration {
+    HumanDuration::new_from_nanos(ns)
+}
+
+/// Create a duration from hours, minutes, and seconds.
+///
+/// ```
+/// # use friendly::temporal::hms;
+/// assert_eq!(hms(5, 32, 10.5).to_string().as_str(), "5h32m10.50s");
+/// ```
+pub fn hms(hours: u64, minutes: u64, secs: f64) -> HumanDuration {
+    if !secs.is_finite() {
+        return HumanDuration::new_from_float(secs, 1e9);
+    }
+    // whole hours and minutes fit, but seconds past i128::MAX ns saturate
+    let whole = hours as i128 * HOUR_NANOS + minutes as i128 * MIN_NANOS;
+    HumanDuration::new_from_nanos(whole.saturating_add((secs * 1e9).round() as i128))
+}
+
+/// Units for bucket labels, largest first.
+const LABEL_UNITS: &[(f64, &str)] = &[
+    (HOUR_SECS, "h"),
+    (MIN_SECS, "min"),
+    (1.0, "s"),
+    (1e-3, "ms"),
+    (1e-6, "μs"),
+    (1e-9, "ns"),
+];
+
+/// Pick the largest label unit in which a duration is at least 1.
+fn label_unit(secs: f64) -> (f64, &'static str) {
+    LABEL_UNITS
+        .iter()
+        .copied()
+        .find(|(u, _)| secs >= *u)
+        .unwrap_or(LABEL_UNITS[LABEL_UNITS.len() - 1])
+}
+
+/// Format a bucket boundary in a unit, without trailing zeros.
+fn label_num(secs: f64, unit: f64) -> String {
+    let (v, prec) = sigscale(secs / unit, 3);
+    format!("{:.*}", trimmed_precision(v, prec), v)
+}
+
+/// Create human-readable labels for duration buckets (e.g. for histogram legends).
+///
+/// The boundaries are sorted (and duplicates dropped) first; the result has one more
+/// label than there are boundaries (or none, if there are no boundaries), except that
+/// there is no “<” label below a zero boundary.  Both ends of each label are displayed
+/// in the same unit, and the open-ended labels use the unit of their neighbors.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use friendly::temporal::bucket_labels;
+/// let labels = bucket_labels(&[
+///     Duration::from_millis(1),
+///     Duration::from_millis(10),
+///     Duration::from_millis(100),
+///     Duration::from_secs(1),
+/// ]);
+/// assert_eq!(labels, vec!["<1 ms", "1–10 ms", "10–100 ms", "100–1000 ms", "≥1000 ms"]);
+/// ```
+pub fn bucket_labels(bounds: &[Duration]) -> Vec<String> {
+    let mut secs: Vec<f64> = bounds.iter().map(|d| d.as_secs_f64()).collect();
+    secs.sort_by(f64::total_cmp);
+    secs.dedup();
+    let (first, last) = match (secs.first(), secs.last()) {
+        (Some(f), Some(l)) => (*f, *l),
+        _ => return Vec::new(),
+    };
+    // each range's unit comes from its lower bound (unless that is zero)
+    let units: Vec<_> = secs
+        .windows(2)
+        .map(|pair| label_unit(if pair[0] > 0.0 { pair[0] } else { pair[1] }))
+        .collect();
+
+    let mut labels = Vec::with_capacity(secs.len() + 1);
+    if first > 0.0 {
+        let (u, ul) = units.first().copied().unwrap_or_else(|| label_unit(first));
+        labels.push(format!("<{} {}", label_num(first, u), ul));
+    }
+    for (pair, (u, ul)) in secs.windows(2).zip(&units) {
+        let (lo, hi) = (pair[0], pair[1]);
+        labels.push(format!(
+            "{}–{} {}",
+            label_num(lo, *u),
+            label_num(hi, *u),
+            ul
+        ));
+    }
+    let (u, ul) = units.last().copied().unwrap_or_else(|| label_unit(last));
+    labels.push(format!("≥{} {}", label_num(last, u), ul));
+    labels
+}
+
+/// Trait for points in time that can be compared with [time_between].
+pub trait Timestamp {
+    /// Get the (signed) number of nanoseconds since the Unix epoch.
+    fn unix_nanos(&self) -> i128;
+
+    /// Get the offset (in seconds) from UTC of this timestamp's time zone.
+    ///
+    /// This is used to determine the calendar date of a timestamp.
+    fn utc_offset(&self) -> i32 {
+        0
+    }
+}
+
+impl Timestamp for SystemTime {
+    fn unix_nanos(&self) -> i128 {
+        match self.duration_since(UNIX_EPOCH) {
+            Ok(d) => d.as_nanos() as i128,
+            Err(e) => -(e.duration().as_nanos() as i128),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> Timestamp for chrono::DateTime<Tz> {
+    fn unix_nanos(&self) -> i128 {
+        self.timestamp() as i128 * 1_000_000_000 + self.timestamp_subsec_nanos() as i128
+    }
+
+    fn utc_offset(&self) -> i32 {
+        use chrono::Offset;
+        self.offset().fix().local_minus_utc()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Timestamp for chrono::NaiveDateTime {
+    fn unix_nanos(&self) -> i128 {
+        self.and_utc().unix_nanos()
+    }
+}
+
+#[cfg(feature = "time")]
+impl Timestamp for time::OffsetDateTime {
+    fn unix_nanos(&self) -> i128 {
+        self.unix_timestamp_nanos()
+    }
+
+    fn utc_offset(&self) -> i32 {
+        self.offset().whole_seconds()
+    }
+}
+
+/// The signed difference between two timestamps, from [time_between].
+///
+/// This displays the magnitude of the difference followed by its direction
+/// (e.g. “3h12m30.00s later” or “5.000s earlier”).  The duration options are the same
+/// as for [HumanDuration].
+pub struct TimeBetween {
+    magnitude: HumanDuration,
+    direction: Ordering,
+}
+
+impl TimeBetween {
+    /// Set whether display is compact.
+    pub fn compact(self, compact: bool) -> TimeBetween {
+        TimeBetween {
+            magnitude: self.magnitude.compact(compact),
+            ..self
+        }
+    }
+
+    /// Set the number of parts to display (0 for all).
+    pub fn parts(self, parts: i32) -> TimeBetween {
+        TimeBetween {
+            magnitude: self.magnitude.parts(parts),
+            ..self
+        }
+    }
+
+    /// Query whether the second timestamp is later than the first.
+    pub fn is_later(&self) -> bool {
+        self.direction == Ordering::Greater
+    }
+
+    /// Get the signed duration from the first timestamp to the second.
+    pub fn duration(&self) -> HumanDuration {
+        let nanos = self.magnitude.nanos;
+        HumanDuration {
+            nanos: if self.direction == Ordering::Less {
+                -nanos
+            } else {
+                nanos
+            },
+            ..self.magnitude
+        }
+    }
+}
+
+/// Describe the time from timestamp `a` to timestamp `b`.
+///
+/// ```
+/// # use std::time::{Duration, SystemTime};
+/// # use friendly::time_between;
+/// let a = SystemTime::now();
+/// let b = a + Duration::from_secs(3 * 3600 + 12 * 60 + 30);
+/// assert_eq!(time_between(a, b).to_string().as_str(), "3h12m30.00s later");
+/// assert_eq!(time_between(b, a).parts(2).to_string().as_str(), "3h12m earlier");
+/// ```
+pub fn time_between<T: Timestamp>(a: T, b: T) -> TimeBetween {
+    let diff = b.unix_nanos() - a.unix_nanos();
+    TimeBetween {
+        magnitude: HumanDuration::new_from_nanos(diff.abs()),
+        direction: diff.cmp(&0),
+    }
+}
+
+impl fmt::Display for TimeBetween {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.direction {
+            Ordering::Equal => f.write_str("at the same time"),
+            Ordering::Greater => write!(f, "{} later", self.magnitude),
+            Ordering::Less => write!(f, "{} earlier", self.magnitude),
+        }
+    }
+}
+
+impl fmt::Display for HumanDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = StackBuf::new();
+        if self.ascii {
+            self.write_display(&mut AsciiWriter::new(&mut buf))?;
+        } else {
+            self.write_display(&mut buf)?;
+        }
+        buf.pad(f)
+    }
+}
+
+impl HumanDuration {
+    /// Write the complete display text to an output.
+    fn write_display(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        if let Some(v) = self.float.filter(|v| !v.is_finite()) {
+            return f.write_str(match v {
+                v if v.is_nan() => "NaN",
+                v if v > 0.0 => "∞",
+                _ => "-∞",
+            });
+        }
+        match self.style {
+            Style::Clock => return write_clock(f, self.nanos, self.clock),
+            Style::DaysClock => return write_days_clock(f, self.nanos),
+            Style::Iso8601 => return write_iso8601(f, self.nanos),
+            Style::Units | Style::Fuzzy => (),
+        }
+
+        let neg = self.nanos < 0;
+        if neg && self.negative == Negative::Minus {
+            f.write_str("-")?;
+        }
+        // the magnitude of i128::MIN is 1ns too large to be an i128
+        let mag = i128::try_from(self.nanos.unsigned_abs()).unwrap_or(i128::MAX);
+        if self.style == Style::Fuzzy {
+            write_fuzzy(f, mag, &self.fuzzy)?;
+        } else {
+            self.write_units(f, mag)?;
+        }
+        if neg && self.negative == Negative::Ago {
+            f.write_str(" ago")?;
+        }
+        Ok(())
+    }
+
+    /// Query whether a unit is within the configured unit range.
+    fn unit_allowed(&self, unit: TimeUnit) -> bool {
+        unit >= self.max_unit && unit <= self.min_unit
+    }
+
+    /// Round a (non-negative) duration to the precision of the last part that will be
+    /// displayed.
+    fn round_last(&self, nanos: i128) -> i128 {
+        if self.rounding == UnitRounding::Floor {
+            return nanos;
+        }
+        // calendar months have no fixed length to round to
+        if let Some(MonthSpan::Calendar(_)) = self.months {
+            return nanos;
+        }
+
+        let mut units = [0i128; 10];
+        let mut n = 0;
+        for (u, _, unit) in PART_UNITS {
+            let skip = match unit {
+                TimeUnit::Year | TimeUnit::Month => self.months.is_none(),
+                TimeUnit::Millisecond | TimeUnit::Microsecond | TimeUnit::Nanosecond => {
+                    !self.subsec
+                }
+                _ => false,
+            };
+            if skip || !self.unit_allowed(*unit) {
+                continue;
+            }
+            // without sub-second parts, seconds are displayed with 2 decimal places
+            units[n] = if *unit == TimeUnit::Second && !self.subsec {
+                u / 100
+            } else {
+                *u
+            };
+            n += 1;
+        }
+        if n == 0 {
+            return nanos;
+        }
+
+        let units = &units[..n];
+        let first = units.iter().position(|u| nanos >= *u).unwrap_or(n - 1);
+        let last = if self.parts > 0 {
+            (first + self.parts as usize - 1).min(n - 1)
+        } else {
+            n - 1
+        };
+        self.rounding.round(nanos, units[last])
+    }
+
+    /// Write the parts of a (non-negative) duration.
+    fn write_units(&self, f: &mut dyn fmt::Write, nanos: i128) -> fmt::Result {
+        if nanos < MIN_NANOS && self.unit_allowed(TimeUnit::Second) {
+            return write!(
+                f,
+                "{}",
+                scalar(nanos as f64 * 1e-9)
+                    .sig_figs(self.nsig)
+                    .suffix("s")
+                    .space(!self.compact)
+                    .decimal_separator(self.point)
+            );
+        }
+
+        let mut pw = PartWriter::new(f, self);
+        let mut rem = self.round_last(nanos);
+
+        if let Some(span) = self.months {
+            let (months, rest) = match span {
+                MonthSpan::Approximate => (rem / MONTH_NANOS, rem % MONTH_NANOS),
+                MonthSpan::Calendar(start) => match calendar_months(start, rem) {
+                    Some((months, rest)) => (months as i128, rest),
+                    // too far out for calendar dates
+                    None => (rem / MONTH_NANOS, rem % MONTH_NANOS),
+                },
+            };
+            let with_years = self.unit_allowed(TimeUnit::Year);
+            let with_months = self.unit_allowed(TimeUnit::Month);
+            if with_years && pw.keep_going() && months >= 12 {
+                pw.put_part((months / 12) as f64, 0, "y", TimeUnit::Year)?;
+            }
+            if with_months && pw.keep_going() && (pw.written > 0 || months > 0) {
+                let months = if with_years { months % 12 } else { months };
+                pw.put_part(months as f64, 0, "mo", TimeUnit::Month)?;
+            }
+            if with_months {
+                rem = rest;
+            }
+        }
+
+        for (len, short, unit) in &PART_UNITS[2..6] {
+            if self.unit_allowed(*unit) && pw.keep_going() && (pw.written > 0 || rem >= *len) {
+                pw.put_part((rem / len) as f64, 0, short, *unit)?;
+                rem %= len;
+            }
+        }
+
+        if !self.unit_allowed(TimeUnit::Second) || !pw.keep_going() {
+            // nothing more to write
+        } else if self.subsec {
+            pw.put_part((rem / SEC_NANOS) as f64, 0, "s", TimeUnit::Second)?;
+            let sub = rem % SEC_NANOS;
+            for (len, short, unit) in &PART_UNITS[7..] {
+                // stop once nothing remains below the previous part
+                if !self.unit_allowed(*unit) || !pw.keep_going() || sub % (len * 1000) == 0 {
+                    break;
+                }
+                pw.put_part((sub % (len * 1000) / len) as f64, 0, short, *unit)?;
+            }
+        } else {
+            pw.put_part(rem as f64 / SEC_NANOS as f64, 2, "s", TimeUnit::Second)?;
+        }
+
+        if pw.written == 0 {
+            // everything was below the smallest unit
+            let (_, short, unit) = PART_UNITS
+                .iter()
+                .find(|(_, _, u)| *u == self.min_unit)
+                .expect("unknown unit");
+            pw.put_part(0.0, 0, short, *unit)?;
+        }
+
+        Ok(())
+    }
+}
+
+struct PartWriter<'a> {
+    fmt: &'a mut dyn fmt::Write,
+    parts: i32,
+    written: i32,
+    compact: bool,
+    point: char,
+    lang: &'static dyn Language,
+}
+
+impl<'a> PartWriter<'a> {
+    fn new(fmt: &'a mut dyn fmt::Write, dur: &HumanDuration) -> PartWriter<'a> {
+        PartWriter {
+            fmt,
+            parts: dur.parts,
+            written: 0,
+            compact: dur.compact,
+            point: dur.point,
+            lang: dur.lang,
+        }
+    }
+
+    fn keep_going(&self) -> bool {
+        self.parts <= 0 || self.written < self.parts
+    }
+
+    fn put_part(
+        &mut self,
+        val: f64,
+        prec: usize,
+        short: &'static str,
+        unit: TimeUnit,
+    ) -> fmt::Result {
+        let v = if prec == 0 { val.floor() } else { val };
+        let spaced = !self.compact && self.lang.spaced();
+        if self.written > 0 && spaced {
+            self.fmt.write_str(" ")?;
+        }
+        let mut num = StackBuf::new();
+        write_fixed(&mut num, v, prec)?;
+        write_number(&mut self.fmt, num.as_str(), None, self.point)?;
+        if self.compact {
+            self.fmt.write_str(short)?;
+        } else {
+            if spaced {
+                self.fmt.write_str(" ")?;
+            }
+            let plural = self.lang.plural(&Operands::of(num.as_str()));
+            self.fmt.write_str(self.lang.time_unit(unit, plural))?;
+        }
+        self.written += 1;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_constructors() {
+    assert_eq!(micros(1.5).to_string().as_str(), "1.500μs");
+    assert_eq!(nanos(-42).to_string().as_str(), "-42.00ns");
+    assert_eq!(hms(0, 90, 0.0).to_string().as_str(), "1h30m0.00s");
+}
+
+#[test]
+fn test_ms() {
+    let ms = seconds(0.324);
+    assert_eq!(ms.to_string().as_str(), "324.0ms");
+}
+
+#[test]
+fn test_seconds() {
+    let d = seconds(5.29314);
+    assert_eq!(d.to_string().as_str(), "5.293s");
+}
+
+#[test]
+fn test_padding() {
+    let d = seconds(5.29314);
+    assert_eq!(format!("[{:>8}]", d).as_str(), "[  5.293s]");
+    let d = seconds(3725.0).style(Style::Clock);
+    assert_eq!(format!("[{:^10}]", d).as_str(), "[ 01:02:05 ]");
+    assert_eq!(format!("{:.2}", d).as_str(), "01:02:05");
+}
+
+#[test]
+fn test_days_clock() {
+    let d = seconds(86400.0 + 2.0 * 3600.0 + 3.0 * 60.0 + 4.5).style(Style::DaysClock);
+    assert_eq!(d.to_string().as_str(), "1d 02:03:04");
+    let d = seconds(3725.0).style(Style::DaysClock);
+    assert_eq!(d.to_string().as_str(), "01:02:05");
+    let d = seconds(-3.0 * 86400.0).style(Style::DaysClock);
+    assert_eq!(d.to_string().as_str(), "-3d 00:00:00");
+}
+
+#[test]
+fn test_ascii() {
+    let d = micros(250.0).ascii(true);
+    assert_eq!(d.to_string().as_str(), "250.0us");
+    let d = seconds(-90.0).compact(false).ascii(true);
+    assert_eq!(d.to_string().as_str(), "-1 minute 30.00 seconds");
+}
+
+#[test]
+fn test_non_finite() {
+    assert_eq!(seconds(f64::INFINITY).to_string().as_str(), "∞");
+    assert_eq!(
+        seconds(f64::NEG_INFINITY).clock().to_string().as_str(),
+        "-∞"
+    );
+    assert_eq!(millis(f64::NAN).to_string().as_str(), "NaN");
+    assert!(seconds(f64::NAN).as_secs_f64().is_nan());
+    let d = hms(1, 0, f64::INFINITY).ascii(true);
+    assert_eq!(d.to_string().as_str(), "inf");
+}
+
+#[test]
+fn test_hms_large() {
+    let d = hms(u64::MAX / 1000, 0, 0.0);
+    assert_eq!(d.as_nanos(), (u64::MAX / 1000) as i128 * HOUR_NANOS);
+    let d = hms(u64::MAX, u64::MAX, 1e40);
+    assert_eq!(d.as_nanos(), i128::MAX);
+}
+
+#[test]
+fn test_saturated() {
+    let d = seconds(-1e30);
+    assert!(d.is_saturated());
+    assert_eq!(d.as_nanos(), -i128::MAX);
+    assert_eq!(d.as_secs_f64(), -1e30);
+    assert!(d.to_string().starts_with("-281318094346013928062976w"));
+    assert!(seconds(f64::NAN).is_saturated());
+    assert!(!seconds(1e20).is_saturated());
+}
+
+#[test]
+fn test_min_nanos() {
+    let d = || HumanDuration::new_from_nanos(i128::MIN);
+    assert!(d().to_string().starts_with('-'));
+    assert!(d().fuzzy().to_string().starts_with('-'));
+    assert!(d().clock().to_string().ends_with(":41:55"));
+    assert!(d()
+        .style(Style::DaysClock)
+        .to_string()
+        .ends_with("d 13:41:55"));
+    assert!(d().iso8601().to_string().starts_with("-P"));
+}
+
+#[test]
+fn test_decimal_comma() {
+    let d = seconds(5.29314).decimal_separator(',');
+    assert_eq!(d.to_string().as_str(), "5,293s");
+    let d = seconds(92.5).compact(false).decimal_separator(',');
+    assert_eq!(d.to_string().as_str(), "1 minute 32,50 seconds");
+}
+
+#[test]
+fn test_lang() {
+    let d = seconds(3725.0).compact(false).lang(&Lang::De);
+    assert_eq!(d.to_string().as_str(), "1 Stunde 2 Minuten 5.00 Sekunden");
+    let d = seconds(3725.0).compact(false).parts(2);
+    assert_eq!(d.to_string().as_str(), "1 hour 2 minutes");
+    let d = seconds(3725.0).compact(false).parts(2).lang(&Lang::Ja);
+    assert_eq!(d.to_string().as_str(), "1時間2分");
+}
+
+#[test]
+fn test_lang_plural_rules() {
+    let d = seconds(5.0 * 3600.0 + 22.0 * 60.0).compact(false).parts(2);
+    assert_eq!(d.lang(&Lang::Ru).to_string().as_str(), "5 часов 22 минуты");
+    let d = seconds(22.0 * 3600.0 + 5.0 * 60.0).compact(false).parts(2);
+    assert_eq!(d.lang(&Lang::Pl).to_string().as_str(), "22 godziny 5 minut");
+}
+
+#[test]
+fn test_clock() {
+    let d = seconds(26.0 * 3600.0 + 5.0).clock();
+    assert_eq!(d.to_string().as_str(), "26:00:05");
+    let d = seconds(185.0).clock();
+    assert_eq!(d.to_string().as_str(), "00:03:05");
+    let d = seconds(185.0).clock().omit_hours(true);
+    assert_eq!(d.to_string().as_str(), "03:05");
+    let d = seconds(-3725.0).clock().pad(false);
+    assert_eq!(d.to_string().as_str(), "-1:02:05");
+}
+
+#[test]
+fn test_iso8601() {
+    let iso = |secs: f64| seconds(secs).iso8601().to_string();
+    assert_eq!(iso(0.0).as_str(), "PT0S");
+    assert_eq!(iso(0.25).as_str(), "PT0.25S");
+    assert_eq!(iso(90000.0).as_str(), "P1DT1H");
+    assert_eq!(iso(2.0 * 86400.0).as_str(), "P2D");
+    assert_eq!(iso(14.0 * 86400.0).as_str(), "P2W");
+    assert_eq!(iso(-90.0).as_str(), "-PT1M30S");
+}
+
+#[test]
+fn test_subsecond_parts() {
+    let d = HumanDuration::new_from_nanos(2 * MIN_NANOS + 3_421_000_005).subsecond_parts(true);
+    assert_eq!(d.to_string().as_str(), "2m3s421ms");
+    let d = d.parts(0);
+    assert_eq!(d.to_string().as_str(), "2m3s421ms0μs5ns");
+    let d = HumanDuration::new_from_nanos(MIN_NANOS + 2_000_000).subsecond_parts(true);
+    let d = d.compact(false).parts(0);
+    assert_eq!(d.to_string().as_str(), "1 minute 0 seconds 2 milliseconds");
+}
+
+#[test]
+fn test_negative() {
+    assert_eq!(seconds(-5.25).to_string().as_str(), "-5.250s");
+    assert_eq!(seconds(-3725.0).to_string().as_str(), "-1h2m5.00s");
+    let d = seconds(-5.25).negative(Negative::Ago).compact(false);
+    assert_eq!(d.to_string().as_str(), "5.250 s ago");
+}
+
+#[test]
+fn test_exact_units() {
+    assert_eq!(seconds(3600.0).to_string().as_str(), "1h0m0.00s");
+    assert_eq!(seconds(7.0 * 86400.0).to_string().as_str(), "1w0d0h");
+}
+
+#[test]
+fn test_years_months() {
+    let d = seconds(400.0 * 86400.0).years_months(true).compact(false);
+    assert_eq!(d.to_string().as_str(), "1 year 1 month 0 weeks");
+    let d = seconds(40.0 * 86400.0).years_months(true);
+    assert_eq!(d.to_string().as_str(), "1mo1w2d");
+}
+
+#[test]
+fn test_fuzzy() {
+    let fz = |secs: f64| seconds(secs).fuzzy().to_string();
+    assert_eq!(fz(50.0).as_str(), "almost a minute");
+    assert_eq!(fz(150.0).as_str(), "over 2 minutes");
+    assert_eq!(fz(5000.0).as_str(), "over an hour");
+    assert_eq!(fz(400.0 * 86400.0).as_str(), "about a year");
+    let d = seconds(-7300.0).fuzzy().negative(Negative::Ago);
+    assert_eq!(d.to_string().as_str(), "about 2 hours ago");
+}
+
+#[test]
+fn test_fuzzy_thresholds() {
+    let th = Fuzzy {
+        few_seconds: 30.0,
+        ..Fuzzy::default()
+    };
+    let d = seconds(20.0).fuzzy_thresholds(th);
+    assert_eq!(d.to_string().as_str(), "a few seconds");
+}
+
+#[test]
+fn test_rounding() {
+    let d = seconds(59.0 * 60.0 + 40.0)
+        .parts(1)
+        .rounding(UnitRounding::Nearest);
+    assert_eq!(d.to_string().as_str(), "1h");
+    let d = seconds(3.0 * 86400.0 + 60.0)
+        .parts(2)
+        .rounding(UnitRounding::Ceil);
+    assert_eq!(d.to_string().as_str(), "3d1h");
+    let d = seconds(90.004).rounding(UnitRounding::Ceil);
+    assert_eq!(d.to_string().as_str(), "1m30.01s");
+}
+
+#[test]
+fn test_unit_range() {
+    let d = seconds(3.0 * 86400.0).max_unit(TimeUnit::Hour).parts(1);
+    assert_eq!(d.to_string().as_str(), "72h");
+    let d = seconds(30.0).min_unit(TimeUnit::Minute);
+    assert_eq!(d.to_string().as_str(), "0m");
+    let d = seconds(150.5).max_unit(TimeUnit::Second);
+    assert_eq!(d.to_string().as_str(), "150.50s");
+    let d = seconds(400.0 * 86400.0).years_months(true);
+    let d = d.max_unit(TimeUnit::Month).min_unit(TimeUnit::Month);
+    assert_eq!(d.to_string().as_str(), "13mo");
+}
+
+#[test]
+fn test_hms() {
+    let d = seconds(5.0 * 3600.0 + 32.0 * 60.0 + 10.5);
+    assert_eq!(d.to_string().as_str(), "5h32m10.50s");
+}
+
+#[test]
+fn test_hm() {
+    let d = seconds(5.0 * 3600.0 + 32.0 * 60.0 + 10.5).parts(2);
+    assert_eq!(d.to_string().as_str(), "5h32m");
+}
+
+#[test]
+fn test_hms_full() {
+    let d = seconds(5.0 * 3600.0 + 32.0 * 60.0 + 10.5).compact(false);
+    assert_eq!(d.to_string().as_str(), "5 hours 32 minutes 10.50 seconds");
+}
+
+#[test]
+fn test_between_subsecond() {
+    let a = UNIX_EPOCH + Duration::from_secs(1000);
+    let b = a + Duration::from_millis(324);
+    assert_eq!(time_between(a, b).to_string().as_str(), "324.0ms later");
+    assert_eq!(time_between(b, a).to_string().as_str(), "324.0ms earlier");
+}
+
+#[test]
+fn test_between_before_epoch() {
+    let a = UNIX_EPOCH - Duration::from_secs(90);
+    let b = UNIX_EPOCH + Duration::from_secs(30);
+    let tb = time_between(a, b).compact(false);
+    assert!(tb.is_later());
+    assert_eq!(tb.to_string().as_str(), "2 minutes 0.00 seconds later");
+}
+
+#[test]
+fn test_between_same() {
+    let a = SystemTime::now();
+    assert_eq!(time_between(a, a).to_string().as_str(), "at the same time");
+}
+
+#[test]
+fn test_between_signed_duration() {
+    let a = UNIX_EPOCH + Duration::from_secs(100);
+    let b = UNIX_EPOCH + Duration::from_secs(95);
+    assert_eq!(time_between(a, b).duration().as_nanos(), -5_000_000_000);
+}
+
+#[test]
+fn test_bucket_empty() {
+    assert!(bucket_labels(&[]).is_empty());
+}
+
+#[test]
+fn test_bucket_zero_start() {
+    let labels = bucket_labels(&[Duration::ZERO, Duration::from_micros(250)]);
+    assert_eq!(labels, vec!["0–250 μs", "≥250 μs"]);
+    assert_eq!(bucket_labels(&[Duration::ZERO]), vec!["≥0 ns"]);
+}
+
+#[test]
+fn test_bucket_fractional() {
+    let labels = bucket_labels(&[Duration::from_millis(2500), Duration::from_secs(90)]);
+    assert_eq!(labels, vec!["<2.5 s", "2.5–90 s", "≥90 s"]);
+}
+
+#[test]
+fn test_bucket_unsorted() {
+    let labels = bucket_labels(&[
+        Duration::from_secs(90),
+        Duration::from_secs(10),
+        Duration::from_secs(90),
+    ]);
+    assert_eq!(labels, vec!["<10 s", "10–90 s", "≥90 s"]);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_between_chrono() {
+    let a = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+    let b = a + chrono::Duration::minutes(192);
+    assert_eq!(
+        time_between(a, b).parts(2).to_string().as_str(),
+        "3h12m later"
+    );
+    let na = a.naive_utc();
+    let nb = b.naive_utc();
+    assert_eq!(
+        time_between(nb, na).parts(2).to_string().as_str(),
+        "3h12m earlier"
+    );
+}
+
+#[test]
+fn test_nanos_exact() {
+    let d = duration(Duration::new(30 * 86400 + 5, 123_456_789));
+    assert_eq!(d.as_nanos(), 2_592_005_123_456_789);
+    let d = duration(Duration::new(65, 1));
+    assert_eq!(d.parts(0).to_string().as_str(), "1m5.00s");
+}
+
+#[test]
+fn test_long_weeks() {
+    let d = duration(Duration::from_secs(
+        100_000 * 7 * 86400 + 86400 * 2 + 3600 * 3,
+    ));
+    assert_eq!(d.to_string().as_str(), "100000w2d3h");
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono() {
+    let dur = chrono::Duration::seconds(1042) + chrono::Duration::milliseconds(314);
+    let d = duration(dur);
+    assert_eq!(d.to_string().as_str(), "17m22.31s");
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_time() {
+    let dur = time::Duration::seconds(1042) + time::Duration::milliseconds(314);
+    let d = duration(dur);
+    assert_eq!(d.to_string().as_str(), "17m22.31s");
+}
+
+#[test]
+fn test_long_agreement() {
+    let d = seconds(3661.0).compact(false);
+    assert_eq!(d.to_string().as_str(), "1 hour 1 minute 1.00 seconds");
+    let d = seconds(7322.0).compact(false);
+    assert_eq!(d.to_string().as_str(), "2 hours 2 minutes 2.00 seconds");
+}
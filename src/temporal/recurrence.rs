@@ -0,0 +1,198 @@
+//! Natural phrasing of recurrence intervals.
+use std::borrow::Cow;
+use std::fmt;
+
+use super::HumanDuration;
+use crate::notation::Notation;
+use crate::quantity::Quantity;
+use crate::sigfig::sigscale;
+
+/// Units for recurrence phrasing: length in seconds, singular and plural names,
+/// and the adverb for a single unit.
+const UNITS: &[(f64, &str, &str, &str)] = &[
+    (604800.0, "week", "weeks", "weekly"),
+    (86400.0, "day", "days", "daily"),
+    (3600.0, "hour", "hours", "hourly"),
+    (60.0, "minute", "minutes", "every minute"),
+    (1.0, "second", "seconds", "every second"),
+    (1e-3, "millisecond", "milliseconds", "every millisecond"),
+];
+
+/// Intervals that are phrased as a number of times per period.
+const TIMES_PER: &[(f64, &str)] = &[
+    (302400.0, "twice a week"),
+    (43200.0, "twice a day"),
+    (28800.0, "three times a day"),
+];
+
+/// A recurrence interval to display as a frequency (from [every]).
+///
+/// Intervals are snapped to a whole number of the largest unit they are close to
+/// (within 5% by default):
+///
+/// ```
+/// # use std::time::Duration;
+/// # use friendly::temporal::every;
+/// assert_eq!(every(Duration::from_secs(300)).to_string().as_str(), "every 5 minutes");
+/// assert_eq!(every(Duration::from_secs(3600)).to_string().as_str(), "hourly");
+/// assert_eq!(every(Duration::from_secs(43200)).to_string().as_str(), "twice a day");
+/// assert_eq!(every(Duration::from_secs(14 * 86400)).to_string().as_str(), "every other week");
+/// ```
+///
+/// Zero intervals are displayed as “continuously”, and infinite ones as “never”.
+/// Counts too large to write exactly use scientific notation (“every 1.653 × 10²⁴
+/// weeks”), and negative or NaN intervals display a [placeholder](Recurrence::placeholder).
+pub struct Recurrence {
+    seconds: f64,
+    tolerance: f64,
+    placeholder: Cow<'static, str>,
+}
+
+/// Describe how often something recurring at an interval happens.
+pub fn every<D: Into<HumanDuration>>(interval: D) -> Recurrence {
+    Recurrence {
+        seconds: interval.into().as_secs_f64(),
+        tolerance: 0.05,
+        placeholder: Cow::Borrowed("—"),
+    }
+}
+
+impl Recurrence {
+    /// Set the relative tolerance for snapping to common intervals.
+    pub fn tolerance(self, tolerance: f64) -> Recurrence {
+        Recurrence { tolerance, ..self }
+    }
+
+    /// Set the text for negative or NaN intervals (“—” by default).
+    ///
+    /// ```
+    /// # use friendly::temporal::{every, seconds};
+    /// let r = every(seconds(-5.0)).placeholder("n/a");
+    /// assert_eq!(r.to_string().as_str(), "n/a");
+    /// ```
+    pub fn placeholder<S: Into<Cow<'static, str>>>(self, text: S) -> Recurrence {
+        Recurrence {
+            placeholder: text.into(),
+            ..self
+        }
+    }
+
+    /// Check if a value is close to a target (relative to the target).
+    fn near(&self, val: f64, target: f64) -> bool {
+        (val - target).abs() <= target * self.tolerance
+    }
+}
+
+impl fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.seconds.is_nan() || self.seconds < 0.0 {
+            return f.write_str(&self.placeholder);
+        }
+        if self.seconds == 0.0 {
+            return f.write_str("continuously");
+        }
+        if self.seconds.is_infinite() {
+            return f.write_str("never");
+        }
+
+        for (secs, phrase) in TIMES_PER {
+            if self.near(self.seconds, *secs) {
+                return f.write_str(phrase);
+            }
+        }
+
+        for (secs, single, plural, adverb) in UNITS {
+            let n = self.seconds / secs;
+            let k = n.round();
+            if k >= 1.0 && self.near(n, k) {
+                // past 2^53, the count is not exact, so don't write all its digits
+                if k > 2f64.powi(53) {
+                    let k = Quantity::decimal(k).notation(Notation::Scientific);
+                    return write!(f, "every {} {}", k, plural);
+                }
+                return match k as u64 {
+                    1 => f.write_str(adverb),
+                    2 => write!(f, "every other {}", single),
+                    k => write!(f, "every {} {}", k, plural),
+                };
+            }
+        }
+
+        // nothing snaps, show fractional seconds
+        let (v, prec) = sigscale(self.seconds, 3);
+        write!(f, "every {:.*} seconds", prec, v)
+    }
+}
+
+#[test]
+fn test_snap() {
+    let r = every(super::seconds(298.0));
+    assert_eq!(r.to_string().as_str(), "every 5 minutes");
+}
+
+#[test]
+fn test_tight_tolerance() {
+    let r = every(super::seconds(298.0)).tolerance(0.001);
+    assert_eq!(r.to_string().as_str(), "every 298 seconds");
+}
+
+#[test]
+fn test_adverbs() {
+    assert_eq!(every(super::seconds(86400.0)).to_string().as_str(), "daily");
+    assert_eq!(
+        every(super::seconds(604800.0)).to_string().as_str(),
+        "weekly"
+    );
+    assert_eq!(
+        every(super::seconds(1.0)).to_string().as_str(),
+        "every second"
+    );
+}
+
+#[test]
+fn test_other_day() {
+    let r = every(super::seconds(2.0 * 86400.0));
+    assert_eq!(r.to_string().as_str(), "every other day");
+}
+
+#[test]
+fn test_times_per() {
+    let r = every(super::seconds(8.0 * 3600.0));
+    assert_eq!(r.to_string().as_str(), "three times a day");
+    let r = every(super::seconds(3.5 * 86400.0));
+    assert_eq!(r.to_string().as_str(), "twice a week");
+}
+
+#[test]
+fn test_mixed_units() {
+    let r = every(super::seconds(90.0 * 60.0));
+    assert_eq!(r.to_string().as_str(), "every 90 minutes");
+    let r = every(super::seconds(10.0 * 86400.0));
+    assert_eq!(r.to_string().as_str(), "every 10 days");
+}
+
+#[test]
+fn test_subsecond() {
+    let r = every(super::seconds(0.25));
+    assert_eq!(r.to_string().as_str(), "every 250 milliseconds");
+    let r = every(super::seconds(0.0));
+    assert_eq!(r.to_string().as_str(), "continuously");
+}
+
+#[test]
+fn test_huge() {
+    let r = every(super::seconds(1e30));
+    assert_eq!(r.to_string().as_str(), "every 1.653 × 10²⁴ weeks");
+    let r = every(super::seconds(1e18));
+    assert_eq!(r.to_string().as_str(), "every 1653439153439 weeks");
+    let r = every(super::seconds(f64::INFINITY));
+    assert_eq!(r.to_string().as_str(), "never");
+}
+
+#[test]
+fn test_invalid() {
+    assert_eq!(every(super::seconds(f64::NAN)).to_string().as_str(), "—");
+    assert_eq!(every(super::seconds(-60.0)).to_string().as_str(), "—");
+    let r = every(super::seconds(f64::NEG_INFINITY)).placeholder(String::from("?"));
+    assert_eq!(r.to_string().as_str(), "?");
+}
@@ -0,0 +1,221 @@
+//! Alternative display styles for durations.
+use std::fmt;
+
+use super::{DAY_NANOS, HOUR_NANOS, MIN_NANOS, MONTH_NANOS, SEC_NANOS, WEEK_NANOS, YEAR_NANOS};
+
+/// Display styles for [HumanDuration](super::HumanDuration).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Style {
+    /// Units for each part (“5h32m10.50s” or “5 hours 32 minutes 10.50 seconds”).
+    #[default]
+    Units,
+    /// A clock-style display (“05:32:10”).
+    Clock,
+    /// Days followed by a clock-style time (“1d 02:03:04”), as in uptime displays.
+    DaysClock,
+    /// An ISO 8601 duration (“PT5H32M10.5S”).
+    Iso8601,
+    /// An approximate phrase (“about an hour”, “almost 2 days”), with the
+    /// thresholds in [Fuzzy].
+    Fuzzy,
+}
+
+/// Ways to display negative durations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Negative {
+    /// A leading minus sign (“-1m30s”).
+    #[default]
+    Minus,
+    /// A trailing direction (“1m30s ago”).
+    Ago,
+}
+
+/// How to round the last displayed part of a duration when later parts are omitted.
+///
+/// This rounds to a whole number of a time unit; [crate::sigfig::Rounding] instead
+/// controls how quantities are rounded to significant figures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnitRounding {
+    /// Round down (“5h59m” for 5h59m40s).
+    #[default]
+    Floor,
+    /// Round to the nearest value (“6h0m” for 5h59m40s).
+    Nearest,
+    /// Round up.
+    Ceil,
+}
+
+impl UnitRounding {
+    /// Round a non-negative number of nanoseconds to a multiple of a unit.
+    pub(crate) fn round(&self, nanos: i128, unit: i128) -> i128 {
+        let n = match self {
+            UnitRounding::Floor => nanos,
+            UnitRounding::Nearest => nanos + unit / 2,
+            UnitRounding::Ceil => nanos + unit - 1,
+        };
+        n / unit * unit
+    }
+}
+
+/// Thresholds for approximate ([Style::Fuzzy]) durations.
+///
+/// Durations are described in the largest unit they are close to, and the fraction
+/// of a unit past a whole number determines the phrasing: below `over` is “about”,
+/// below `almost` is “over”, and otherwise it is “almost” the next whole number.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fuzzy {
+    /// Durations shorter than this many seconds are “a few seconds”.
+    pub few_seconds: f64,
+    /// The fraction of a unit at which “about” becomes “over”.
+    pub over: f64,
+    /// The fraction of a unit at which “over” becomes “almost”.
+    pub almost: f64,
+}
+
+impl Default for Fuzzy {
+    fn default() -> Self {
+        Fuzzy {
+            few_seconds: 10.0,
+            over: 0.25,
+            almost: 0.75,
+        }
+    }
+}
+
+/// Units for approximate durations, largest first, with their singular phrases.
+const FUZZY_UNITS: &[(i128, &str, &str)] = &[
+    (YEAR_NANOS, "a year", "years"),
+    (MONTH_NANOS, "a month", "months"),
+    (DAY_NANOS, "a day", "days"),
+    (HOUR_NANOS, "an hour", "hours"),
+    (MIN_NANOS, "a minute", "minutes"),
+    (SEC_NANOS, "a second", "seconds"),
+];
+
+/// Write a (non-negative) duration as an approximate phrase.
+pub(crate) fn write_fuzzy(f: &mut dyn fmt::Write, nanos: i128, opts: &Fuzzy) -> fmt::Result {
+    if (nanos as f64) < opts.few_seconds * SEC_NANOS as f64 {
+        return f.write_str("a few seconds");
+    }
+    let (unit, one, many) = FUZZY_UNITS
+        .iter()
+        .find(|(u, _, _)| nanos as f64 / *u as f64 >= opts.almost)
+        .unwrap_or(&FUZZY_UNITS[FUZZY_UNITS.len() - 1]);
+    let val = nanos as f64 / *unit as f64;
+    let whole = val.floor();
+    let frac = val - whole;
+    let (word, n) = if whole < 1.0 || frac >= opts.almost {
+        ("almost", whole + 1.0)
+    } else if frac >= opts.over {
+        ("over", whole)
+    } else {
+        ("about", whole)
+    };
+    if n == 1.0 {
+        write!(f, "{} {}", word, one)
+    } else {
+        write!(f, "{} {} {}", word, n, many)
+    }
+}
+
+/// Options for clock-style ([Style::Clock]) durations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ClockOpts {
+    /// Zero-pad the leading field.
+    pub pad: bool,
+    /// Display hours even when they are zero.
+    pub hours: bool,
+}
+
+impl Default for ClockOpts {
+    fn default() -> Self {
+        ClockOpts {
+            pad: true,
+            hours: true,
+        }
+    }
+}
+
+/// Write a duration in clock style.  Hours are not wrapped into days, and
+/// fractional seconds are truncated.
+pub(crate) fn write_clock(f: &mut dyn fmt::Write, nanos: i128, opts: ClockOpts) -> fmt::Result {
+    if nanos < 0 {
+        f.write_str("-")?;
+    }
+    let nanos = nanos.unsigned_abs();
+    let hours = nanos / HOUR_NANOS as u128;
+    let mins = nanos % HOUR_NANOS as u128 / MIN_NANOS as u128;
+    let secs = nanos % MIN_NANOS as u128 / SEC_NANOS as u128;
+    let width = if opts.pad { 2 } else { 1 };
+    if hours > 0 || opts.hours {
+        write!(f, "{:0w$}:{:02}:{:02}", hours, mins, secs, w = width)
+    } else {
+        write!(f, "{:0w$}:{:02}", mins, secs, w = width)
+    }
+}
+
+/// Write a duration as days followed by a zero-padded clock time.  Durations of
+/// less than a day are written as the time alone.
+pub(crate) fn write_days_clock(f: &mut dyn fmt::Write, nanos: i128) -> fmt::Result {
+    if nanos < 0 {
+        f.write_str("-")?;
+    }
+    let nanos = nanos.unsigned_abs();
+    let days = nanos / DAY_NANOS as u128;
+    if days > 0 {
+        write!(f, "{}d ", days)?;
+    }
+    write_clock(f, (nanos % DAY_NANOS as u128) as i128, ClockOpts::default())
+}
+
+/// Write a duration in ISO 8601 format.
+///
+/// Durations that are a whole number of weeks use the week form (“P2W”); others are
+/// written with days and time parts (“P1DT2H”).  Negative durations have a leading
+/// minus sign, as permitted by ISO 8601-2.
+pub(crate) fn write_iso8601(f: &mut dyn fmt::Write, nanos: i128) -> fmt::Result {
+    if nanos < 0 {
+        f.write_str("-")?;
+    }
+    let nanos = nanos.unsigned_abs();
+    f.write_str("P")?;
+    if nanos > 0 && nanos.is_multiple_of(WEEK_NANOS as u128) {
+        return write!(f, "{}W", nanos / WEEK_NANOS as u128);
+    }
+
+    let days = nanos / DAY_NANOS as u128;
+    if days > 0 {
+        write!(f, "{}D", days)?;
+    }
+    // less than a day, so it fits
+    let time = (nanos % DAY_NANOS as u128) as i128;
+    if time == 0 && days > 0 {
+        return Ok(());
+    }
+
+    f.write_str("T")?;
+    let hours = time / HOUR_NANOS;
+    let mins = time % HOUR_NANOS / MIN_NANOS;
+    let secs = time % MIN_NANOS / SEC_NANOS;
+    let frac = time % SEC_NANOS;
+    if hours > 0 {
+        write!(f, "{}H", hours)?;
+    }
+    if mins > 0 {
+        write!(f, "{}M", mins)?;
+    }
+    if secs > 0 || frac > 0 || time == 0 {
+        write!(f, "{}", secs)?;
+        if frac > 0 {
+            // trim trailing zeros from the nanoseconds
+            let (mut frac, mut width) = (frac, 9);
+            while frac % 10 == 0 {
+                frac /= 10;
+                width -= 1;
+            }
+            write!(f, ".{:0w$}", frac, w = width)?;
+        }
+        f.write_str("S")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,240 @@
+//! Relative display of timestamps.
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+use super::calendar::CivilDate;
+use super::{Timestamp, DAY_NANOS, SEC_NANOS};
+use crate::ordinal::ordinal_suffix;
+use crate::relative::Relative;
+
+/// Formats for displaying absolute dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateFormat {
+    /// ISO 8601 dates (“2024-03-03”).
+    Iso,
+    /// Dates with abbreviated month names (“Mar 3, 2024”).
+    Short,
+    /// Dates with full month names (“March 3, 2024”).
+    Long,
+}
+
+impl DateFormat {
+    /// Write a date, optionally with an ordinal day (“March 3rd”) if the format has
+    /// month names.
+    pub(crate) fn write_date(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        date: CivilDate,
+        ordinal: bool,
+    ) -> fmt::Result {
+        let sfx = if ordinal {
+            ordinal_suffix(date.day as i64)
+        } else {
+            ""
+        };
+        match self {
+            DateFormat::Iso => write!(f, "{:04}-{:02}-{:02}", date.year, date.month, date.day),
+            DateFormat::Short => {
+                let month = &date.month_name()[..3];
+                write!(f, "{} {}{}, {}", month, date.day, sfx, date.year)
+            }
+            DateFormat::Long => {
+                write!(
+                    f,
+                    "{} {}{}, {}",
+                    date.month_name(),
+                    date.day,
+                    sfx,
+                    date.year
+                )
+            }
+        }
+    }
+}
+
+/// A timestamp displayed relative to the current time (from [timestamp]).
+///
+/// Timestamps within a cutoff (30 days by default) of the current time are displayed
+/// relative to it (“3 weeks ago”, “in 2 hours”); timestamps further away are displayed
+/// as absolute dates in the timestamp's own UTC offset (“Mar 3, 2024”):
+///
+/// ```
+/// # use std::time::{Duration, UNIX_EPOCH};
+/// # use friendly::temporal::*;
+/// let now = UNIX_EPOCH + Duration::from_secs(1_709_856_000); // 2024-03-08
+/// let t = now - Duration::from_secs(3 * 7 * 86400);
+/// assert_eq!(timestamp(t).relative_to(now).to_string().as_str(), "3 weeks ago");
+/// let t = now - Duration::from_secs(5 * 86400);
+/// let ts = timestamp(t).relative_to(now).cutoff(Duration::from_secs(86400));
+/// assert_eq!(ts.to_string().as_str(), "Mar 3, 2024");
+/// ```
+pub struct HumanTimestamp {
+    nanos: i128,
+    offset: i32,
+    now: i128,
+    cutoff: Option<Duration>,
+    format: DateFormat,
+    phrase: bool,
+}
+
+/// Display a timestamp relative to the current time.
+pub fn timestamp<T: Timestamp>(t: T) -> HumanTimestamp {
+    HumanTimestamp {
+        nanos: t.unix_nanos(),
+        offset: t.utc_offset(),
+        now: SystemTime::now().unix_nanos(),
+        cutoff: Some(Duration::from_secs(30 * 86400)),
+        format: DateFormat::Short,
+        phrase: false,
+    }
+}
+
+impl HumanTimestamp {
+    /// Display relative to a specific time instead of the current time.
+    pub fn relative_to<T: Timestamp>(self, now: T) -> HumanTimestamp {
+        HumanTimestamp {
+            now: now.unix_nanos(),
+            ..self
+        }
+    }
+
+    /// Set the cutoff beyond which absolute dates are displayed.
+    pub fn cutoff(self, cutoff: Duration) -> HumanTimestamp {
+        HumanTimestamp {
+            cutoff: Some(cutoff),
+            ..self
+        }
+    }
+
+    /// Always display relative times, no matter how far away.
+    pub fn no_cutoff(self) -> HumanTimestamp {
+        HumanTimestamp {
+            cutoff: None,
+            ..self
+        }
+    }
+
+    /// Set the format for absolute dates.
+    pub fn date_format(self, format: DateFormat) -> HumanTimestamp {
+        HumanTimestamp { format, ..self }
+    }
+
+    /// Set whether absolute dates are written as phrases (“on Mar 3, 2024”), to read
+    /// naturally in the same places as relative times.
+    pub fn phrase(self, phrase: bool) -> HumanTimestamp {
+        HumanTimestamp { phrase, ..self }
+    }
+}
+
+impl fmt::Display for HumanTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let diff = self.now - self.nanos;
+        match self.cutoff {
+            Some(c) if diff.unsigned_abs() >= c.as_nanos() => {
+                let local = self.nanos + self.offset as i128 * SEC_NANOS;
+                let days = local.div_euclid(DAY_NANOS) as i64;
+                if self.phrase {
+                    f.write_str("on ")?;
+                }
+                self.format
+                    .write_date(f, CivilDate::from_unix_days(days), false)
+            }
+            _ => write!(f, "{}", Relative::new(diff)),
+        }
+    }
+}
+
+#[cfg(test)]
+use std::time::UNIX_EPOCH;
+
+#[cfg(test)]
+fn test_now() -> SystemTime {
+    // 2024-03-08T00:00:00Z
+    UNIX_EPOCH + Duration::from_secs(1_709_856_000)
+}
+
+#[test]
+fn test_just_now() {
+    let ts = timestamp(test_now()).relative_to(test_now());
+    assert_eq!(ts.to_string().as_str(), "just now");
+}
+
+#[test]
+fn test_future() {
+    let t = test_now() + Duration::from_secs(2 * 3600 + 5);
+    let ts = timestamp(t).relative_to(test_now());
+    assert_eq!(ts.to_string().as_str(), "in 2 hours");
+}
+
+#[test]
+fn test_singular() {
+    let t = test_now() - Duration::from_secs(61);
+    let ts = timestamp(t).relative_to(test_now());
+    assert_eq!(ts.to_string().as_str(), "1 minute ago");
+}
+
+#[test]
+fn test_no_cutoff() {
+    let t = test_now() - Duration::from_secs(800 * 86400);
+    let ts = timestamp(t).relative_to(test_now()).no_cutoff();
+    assert_eq!(ts.to_string().as_str(), "2 years ago");
+}
+
+#[test]
+fn test_default_cutoff() {
+    let t = test_now() - Duration::from_secs(40 * 86400);
+    let ts = timestamp(t).relative_to(test_now());
+    assert_eq!(ts.to_string().as_str(), "Jan 28, 2024");
+}
+
+#[test]
+fn test_formats() {
+    let t = test_now() - Duration::from_secs(5 * 86400);
+    let ts = timestamp(t).relative_to(test_now()).cutoff(Duration::ZERO);
+    let ts = ts.date_format(DateFormat::Iso);
+    assert_eq!(ts.to_string().as_str(), "2024-03-03");
+    let ts = ts.date_format(DateFormat::Long);
+    assert_eq!(ts.to_string().as_str(), "March 3, 2024");
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono_offset() {
+    use chrono::TimeZone;
+    // 2024-03-03T02:00:00Z is still March 2 in New York
+    let tz = chrono::FixedOffset::west_opt(5 * 3600).unwrap();
+    let t = tz.timestamp_opt(1_709_431_200, 0).unwrap();
+    let ts = timestamp(t).relative_to(test_now()).cutoff(Duration::ZERO);
+    assert_eq!(ts.to_string().as_str(), "Mar 2, 2024");
+}
+
+#[test]
+fn test_phrase() {
+    let t = test_now() - Duration::from_secs(40 * 86400);
+    let ts = timestamp(t).relative_to(test_now()).phrase(true);
+    let ts = ts.date_format(DateFormat::Iso);
+    assert_eq!(ts.to_string().as_str(), "on 2024-01-28");
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono_naive() {
+    let t = chrono::DateTime::from_timestamp(1_709_856_000 - 2 * 86400, 0).unwrap();
+    let ts = timestamp(t.naive_utc()).relative_to(test_now());
+    assert_eq!(ts.to_string().as_str(), "2 days ago");
+    let ts = timestamp(t).relative_to(test_now()).cutoff(Duration::ZERO);
+    assert_eq!(ts.phrase(true).to_string().as_str(), "on Mar 6, 2024");
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_time_offset() {
+    // 2024-03-03T02:00:00Z is still March 2 in New York
+    let tz = time::UtcOffset::from_hms(-5, 0, 0).unwrap();
+    let t = time::OffsetDateTime::from_unix_timestamp(1_709_431_200).unwrap();
+    let ts = timestamp(t.to_offset(tz)).relative_to(test_now());
+    assert_eq!(
+        ts.cutoff(Duration::ZERO).to_string().as_str(),
+        "Mar 2, 2024"
+    );
+}
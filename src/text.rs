@@ -0,0 +1,146 @@
+//! Display of text shortened to a width.
+use std::borrow::Cow;
+use std::fmt;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::buffer::pad_parts;
+
+/// Text truncated to a maximum display width, with an ellipsis if it was shortened.
+///
+/// Widths are measured in terminal columns, so wide characters (such as CJK) count
+/// as two, and text is never cut inside a grapheme cluster:
+///
+/// ```
+/// # use friendly::truncate;
+/// assert_eq!(truncate("quarterly-report.pdf", 12).to_string().as_str(), "quarterly-r…");
+/// assert_eq!(truncate("short.txt", 12).to_string().as_str(), "short.txt");
+/// assert_eq!(truncate("日本語のファイル", 7).to_string().as_str(), "日本語…");
+/// ```
+///
+/// It can optionally cut at a word boundary instead:
+///
+/// ```
+/// # use friendly::truncate;
+/// let t = truncate("the quick brown fox", 14).words(true);
+/// assert_eq!(t.to_string().as_str(), "the quick…");
+/// ```
+///
+/// Padding to a width (`{:>12}`) is also by display width, whether or not the text
+/// was shortened.
+#[derive(Debug, Clone)]
+pub struct Truncated<'a> {
+    text: &'a str,
+    width: usize,
+    words: bool,
+    ellipsis: Cow<'static, str>,
+}
+
+/// Truncate text to a maximum display width.
+pub fn truncate(text: &str, max_width: usize) -> Truncated<'_> {
+    Truncated::new(text, max_width)
+}
+
+impl<'a> Truncated<'a> {
+    /// Create a new truncated text.
+    pub fn new(text: &'a str, width: usize) -> Self {
+        Truncated {
+            text,
+            width,
+            words: false,
+            ellipsis: Cow::Borrowed("…"),
+        }
+    }
+
+    /// Set whether to prefer cutting at a word boundary.
+    pub fn words(self, words: bool) -> Self {
+        Truncated { words, ..self }
+    }
+
+    /// Set the ellipsis marking shortened text (“…” by default).
+    pub fn ellipsis<S: Into<Cow<'static, str>>>(self, ellipsis: S) -> Self {
+        Truncated {
+            ellipsis: ellipsis.into(),
+            ..self
+        }
+    }
+
+    /// Get the text to keep before the ellipsis, or `None` if the text fits.
+    fn kept(&self) -> Option<&'a str> {
+        if self.text.width() <= self.width {
+            return None;
+        }
+        let budget = self.width.saturating_sub(self.ellipsis.width());
+        let mut used = 0;
+        let mut cut = 0;
+        for (i, g) in self.text.grapheme_indices(true) {
+            used += g.width();
+            if used > budget {
+                break;
+            }
+            cut = i + g.len();
+        }
+        if self.words && !self.text[cut..].starts_with(char::is_whitespace) {
+            // back up to the start of the word we are in the middle of
+            let start = self
+                .text
+                .split_word_bound_indices()
+                .map(|(i, _)| i)
+                .take_while(|i| *i <= cut)
+                .last()
+                .unwrap_or(0);
+            let word = self.text[..start].trim_end();
+            if !word.is_empty() {
+                return Some(word);
+            }
+        }
+        Some(self.text[..cut].trim_end())
+    }
+}
+
+impl fmt::Display for Truncated<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kept() {
+            None => pad_parts(f, &[self.text], self.text.width()),
+            Some(_) if self.width < self.ellipsis.width() => pad_parts(f, &[], 0),
+            Some(kept) => {
+                let width = kept.width() + self.ellipsis.width();
+                pad_parts(f, &[kept, &self.ellipsis], width)
+            }
+        }
+    }
+}
+
+#[test]
+fn test_graphemes() {
+    // the family emoji is one grapheme of several code points
+    let t = truncate("ab👨‍👩‍👧cd", 4);
+    assert_eq!(t.to_string().as_str(), "ab…");
+    let t = truncate("ab👨‍👩‍👧cd", 5);
+    assert_eq!(t.to_string().as_str(), "ab👨‍👩‍👧…");
+}
+
+#[test]
+fn test_tiny_width() {
+    assert_eq!(truncate("hello", 1).to_string().as_str(), "…");
+    assert_eq!(truncate("hello", 0).to_string().as_str(), "");
+    let t = truncate("hello", 2).ellipsis("...");
+    assert_eq!(t.to_string().as_str(), "");
+}
+
+#[test]
+fn test_long_word() {
+    let t = truncate("supercalifragilistic", 8).words(true);
+    assert_eq!(t.to_string().as_str(), "superca…");
+}
+
+#[test]
+fn test_padding() {
+    let t = truncate("hello world", 5);
+    assert_eq!(format!("[{:>10}]", t).as_str(), "[     hell…]");
+    let t = truncate("日本", 10);
+    assert_eq!(format!("[{:<6.1}]", t).as_str(), "[日本  ]");
+    let t = truncate("hello", 4).ellipsis(String::from("~"));
+    assert_eq!(format!("[{:^6}]", t).as_str(), "[ hel~ ]");
+}
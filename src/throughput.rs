@@ -0,0 +1,128 @@
+//! Measurement of transfer rates.
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::quantity::Quantity;
+use crate::scale::Binary;
+
+/// A tracker for the current throughput of a transfer.
+///
+/// The rate is an exponential moving average of the bytes recorded over time, so it
+/// follows recent changes in speed while smoothing out bursts.  It displays as a
+/// [rate](crate::rate):
+///
+/// ```
+/// # use std::time::{Duration, Instant};
+/// # use friendly::Throughput;
+/// let start = Instant::now();
+/// let mut tp = Throughput::starting_at(start);
+/// for i in 1..=20 {
+///     tp.record_at(4_928_307, start + Duration::from_secs(i));
+/// }
+/// assert_eq!(tp.to_string().as_str(), "4.700 MiB/s");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Throughput {
+    window: Duration,
+    last: Instant,
+    pending: u64,
+    rate: Option<f64>,
+}
+
+impl Throughput {
+    /// Create a new tracker, starting now.
+    pub fn new() -> Throughput {
+        Throughput::starting_at(Instant::now())
+    }
+
+    /// Create a new tracker, starting at a specific time.
+    pub fn starting_at(start: Instant) -> Throughput {
+        Throughput {
+            window: Duration::from_secs(5),
+            last: start,
+            pending: 0,
+            rate: None,
+        }
+    }
+
+    /// Set the averaging window (5 seconds by default).  Recent rates are weighted
+    /// more heavily; a record from one window ago has about 37% of the weight of one
+    /// recorded now.
+    pub fn window(self, window: Duration) -> Throughput {
+        Throughput { window, ..self }
+    }
+
+    /// Record that a number of bytes were transferred since the last record.
+    pub fn record(&mut self, bytes: u64) {
+        self.record_at(bytes, Instant::now())
+    }
+
+    /// Record that a number of bytes were transferred by a specific time.
+    pub fn record_at(&mut self, bytes: u64, at: Instant) {
+        self.pending += bytes;
+        let dt = at.saturating_duration_since(self.last).as_secs_f64();
+        if dt <= 0.0 {
+            // no time has passed, so count the bytes in the next interval
+            return;
+        }
+        let current = self.pending as f64 / dt;
+        self.pending = 0;
+        let alpha = 1.0 - (-dt / self.window.as_secs_f64()).exp();
+        self.rate = Some(match self.rate {
+            Some(rate) => rate + alpha * (current - rate),
+            None => current,
+        });
+        self.last = at;
+    }
+
+    /// Get the current rate in bytes per second, if anything has been recorded.
+    pub fn bytes_per_sec(&self) -> Option<f64> {
+        self.rate
+    }
+
+    /// Get the current rate for display.
+    pub fn rate(&self) -> Quantity<f64, Binary> {
+        crate::rate(self.rate.unwrap_or(0.0), Duration::from_secs(1))
+    }
+}
+
+impl Default for Throughput {
+    fn default() -> Throughput {
+        Throughput::new()
+    }
+}
+
+impl fmt::Display for Throughput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.rate().fmt(f)
+    }
+}
+
+#[test]
+fn test_follows_change() {
+    let start = Instant::now();
+    let mut tp = Throughput::starting_at(start).window(Duration::from_secs(2));
+    tp.record_at(1000, start + Duration::from_secs(1));
+    assert_eq!(tp.bytes_per_sec(), Some(1000.0));
+    for i in 2..=30 {
+        tp.record_at(2000, start + Duration::from_secs(i));
+    }
+    let rate = tp.bytes_per_sec().unwrap();
+    assert!((rate - 2000.0).abs() < 1.0, "{}", rate);
+}
+
+#[test]
+fn test_same_instant() {
+    let start = Instant::now();
+    let mut tp = Throughput::starting_at(start);
+    tp.record_at(500, start);
+    tp.record_at(500, start + Duration::from_secs(1));
+    assert_eq!(tp.bytes_per_sec(), Some(1000.0));
+}
+
+#[test]
+fn test_empty() {
+    let tp = Throughput::new();
+    assert_eq!(tp.bytes_per_sec(), None);
+    assert_eq!(tp.to_string().as_str(), "0.0000 B/s");
+}
@@ -0,0 +1,55 @@
+//! Human-readable [tracing] fields (with the `tracing` feature).
+//!
+//! The functions in this module wrap values so spans and events record them in their
+//! human-readable form, rather than as raw numbers:
+//!
+//! ```
+//! # use std::time::Duration;
+//! let n = 13200u64;
+//! tracing::info!(size = friendly::tracing::bytes(n), "downloaded file");
+//! let span = tracing::info_span!("fetch", elapsed = tracing::field::Empty);
+//! span.record("elapsed", friendly::tracing::duration(Duration::from_secs(90)));
+//! ```
+//!
+//! With the `fmt` layer of `tracing-subscriber`, the event above renders as
+//! `downloaded file size=12.89 KiB`.  Any other display type from this crate can be
+//! recorded with [field].
+use std::fmt::Display;
+
+use ::tracing::field::{display, DisplayValue};
+
+use crate::quantity::{QVal, Quantity};
+use crate::scale::{Binary, Decimal};
+use crate::temporal::HumanDuration;
+
+/// Record a value with its [Display] implementation.
+pub fn field<T: Display>(val: T) -> DisplayValue<T> {
+    display(val)
+}
+
+/// Record a number of bytes (see [crate::bytes]).
+pub fn bytes<V: QVal>(val: V) -> DisplayValue<Quantity<V, Binary>> {
+    display(crate::bytes(val))
+}
+
+/// Record an auto-scaled number (see [crate::scalar]).
+pub fn scalar<V: QVal>(val: V) -> DisplayValue<Quantity<V, Decimal>> {
+    display(crate::scalar(val))
+}
+
+/// Record an auto-scaled integer (see [crate::integer]).
+pub fn integer<V: QVal>(val: V) -> DisplayValue<Quantity<V, Decimal>> {
+    display(crate::integer(val))
+}
+
+/// Record a duration (see [crate::duration]).
+pub fn duration<D: Into<HumanDuration>>(val: D) -> DisplayValue<HumanDuration> {
+    display(crate::duration(val))
+}
+
+#[test]
+fn test_recorded_form() {
+    // values are recorded through their Debug implementation
+    assert_eq!(format!("{:?}", bytes(13200)).as_str(), "12.89 KiB");
+    assert_eq!(format!("{:?}", integer(15_250)).as_str(), "15.25 k");
+}
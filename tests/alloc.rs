@@ -0,0 +1,61 @@
+//! Tests that formatting into a fixed buffer does not allocate.
+//!
+//! This installs a counting global allocator, so it is kept in its own test binary.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::Write;
+use std::time::Duration;
+
+use friendly::buffer::FixedBuf;
+use friendly::{bytes, duration, scalar};
+
+thread_local! {
+    static ALLOCS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// An allocator that counts allocations on the current thread.
+struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCS.try_with(|n| n.set(n.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOC: Counting = Counting;
+
+/// Count the allocations made by a function on this thread.
+fn allocations<F: FnOnce()>(f: F) -> usize {
+    let start = ALLOCS.with(|n| n.get());
+    f();
+    ALLOCS.with(|n| n.get()) - start
+}
+
+#[test]
+fn test_no_allocation() {
+    // make sure allocations are actually being counted
+    assert_eq!(allocations(|| drop(String::from("x"))), 1);
+
+    let mut buf = FixedBuf::<64>::new();
+    let n = allocations(|| {
+        for i in 0..100u64 {
+            buf.clear();
+            bytes(i * 13_200).write_to(&mut buf).unwrap();
+            buf.clear();
+            scalar(i as f64 * 0.0234)
+                .suffix("s")
+                .write_to(&mut buf)
+                .unwrap();
+            buf.clear();
+            write!(buf, "{}", duration(Duration::from_secs(i * 3725))).unwrap();
+        }
+    });
+    assert_eq!(n, 0);
+    assert_eq!(buf.as_str(), "4d6h26m");
+}
@@ -0,0 +1,37 @@
+//! Tests for the `FriendlyDisplay` derive.
+#![cfg(feature = "derive")]
+use friendly::FriendlyDisplay;
+
+#[derive(FriendlyDisplay)]
+struct Status {
+    #[friendly(bytes)]
+    size: u64,
+    #[friendly(label = "file")]
+    name: &'static str,
+    #[friendly(skip)]
+    #[allow(dead_code)]
+    id: u32,
+}
+
+const STATUS: Status = Status {
+    size: 13200,
+    name: "x",
+    id: 7,
+};
+
+#[test]
+fn test_fields() {
+    assert_eq!(STATUS.to_string().as_str(), "size: 12.89 KiB, file: x");
+}
+
+#[test]
+fn test_padding() {
+    assert_eq!(
+        format!("[{:>30}]", STATUS).as_str(),
+        "[      size: 12.89 KiB, file: x]"
+    );
+    assert_eq!(
+        format!("[{:*<26.3}]", STATUS).as_str(),
+        "[size: 12.89 KiB, file: x**]"
+    );
+}